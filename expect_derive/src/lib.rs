@@ -0,0 +1,116 @@
+//! Derive macros for the `expect` crate. Enable them through its `derive` feature rather than
+//! depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericParam, Index,
+};
+
+/// Derives `expect::matchers::float::ApproxEq` for a struct, comparing its fields one by one.
+///
+/// Every field needs to implement `ApproxEq` too. When a field is out of tolerance, the mismatch
+/// reports its name.
+///
+/// # Examples
+///
+/// ```
+/// use expect::{expect, matchers::float::{be_close_to, ApproxEq}};
+///
+/// #[derive(Debug, ApproxEq)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// let failure = expect(&Point { x: 1.0, y: 2.5 })
+///     .try_to(be_close_to(Point { x: 1.0, y: 2.0 }).within(0.1))
+///     .unwrap_err();
+/// assert!(failure.reason.unwrap().starts_with("at .y (was 2.5, expected 2.0)"));
+/// ```
+///
+/// Enums and unions are rejected:
+///
+/// ```compile_fail
+/// use expect::matchers::float::ApproxEq;
+///
+/// #[derive(ApproxEq)]
+/// enum Shape {
+///     Circle(f64),
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use expect::matchers::float::ApproxEq;
+///
+/// #[derive(ApproxEq)]
+/// union Bits {
+///     float: f64,
+/// }
+/// ```
+#[proc_macro_derive(ApproxEq)]
+pub fn derive_approx_eq(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "ApproxEq can only be derived for structs",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    let checks = field_checks(fields);
+
+    for param in &mut input.generics.params {
+        if let GenericParam::Type(param) = param {
+            param
+                .bounds
+                .push(parse_quote!(::expect::matchers::float::ApproxEq));
+        }
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics ::expect::matchers::float::ApproxEq for #name #ty_generics #where_clause {
+            fn approx_eq(
+                &self,
+                expected: &Self,
+                tolerance: ::expect::matchers::float::Tolerance,
+            ) -> ::std::result::Result<(), ::expect::matchers::float::ApproxMismatch> {
+                #(#checks)*
+                ::std::result::Result::Ok(())
+            }
+        }
+    };
+    expanded.into()
+}
+
+fn field_checks(fields: &Fields) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let (member, name) = match &field.ident {
+                Some(ident) => (quote!(#ident), ident.unraw().to_string()),
+                None => {
+                    let index = Index::from(index);
+                    (quote!(#index), index.index.to_string())
+                }
+            };
+            quote! {
+                ::expect::matchers::float::ApproxEq::approx_eq(
+                    &self.#member,
+                    &expected.#member,
+                    tolerance,
+                )
+                .map_err(|mismatch| mismatch.at_field(#name))?;
+            }
+        })
+        .collect()
+}
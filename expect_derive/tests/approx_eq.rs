@@ -0,0 +1,89 @@
+use expect::{
+    expect,
+    matchers::float::{be_close_to, ApproxEq},
+};
+
+#[derive(Debug, ApproxEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, ApproxEq)]
+struct Pair<T>(T, T);
+
+#[derive(Debug, ApproxEq)]
+struct Labelled<T> {
+    value: T,
+    samples: Vec<f32>,
+}
+
+#[derive(Debug, ApproxEq)]
+struct Unit;
+
+#[derive(Debug, ApproxEq)]
+struct Keywords {
+    r#type: f64,
+}
+
+fn reason<T: ApproxEq + std::fmt::Debug>(actual: T, expected: T) -> Option<String> {
+    expect(&actual)
+        .try_to(be_close_to(expected).within(0.1))
+        .err()
+        .and_then(|failure| failure.reason)
+}
+
+#[test]
+fn should_compare_named_fields() {
+    expect(&Point { x: 1.0, y: 2.0 }).to(be_close_to(Point { x: 1.0, y: 2.05 }).within(0.1));
+    assert_eq!(
+        reason(Point { x: 1.0, y: 2.5 }, Point { x: 1.0, y: 2.0 }),
+        Some(String::from(
+            "at .y (was 2.5, expected 2.0): delta was 0.5, but absolute tolerance is 0.1"
+        ))
+    );
+}
+
+#[test]
+fn should_compare_tuple_fields_by_index() {
+    expect(&Pair(1.0, 2.0)).to(be_close_to(Pair(1.05, 2.0)).within(0.1));
+    assert_eq!(
+        reason(Pair(1.0, 2.0), Pair(1.0, 3.0)),
+        Some(String::from(
+            "at .1 (was 2.0, expected 3.0): delta was 1.0, but absolute tolerance is 0.1"
+        ))
+    );
+}
+
+#[test]
+fn should_support_generic_structs() {
+    let actual = Labelled {
+        value: Pair(1.0f32, 2.0),
+        samples: vec![0.5, 1.0],
+    };
+    let expected = Labelled {
+        value: Pair(1.0f32, 2.0),
+        samples: vec![0.5, 1.5],
+    };
+    assert_eq!(
+        reason(actual, expected),
+        Some(String::from(
+            "at .samples[1] (was 1.0, expected 1.5): delta was 0.5, but absolute tolerance is 0.1"
+        ))
+    );
+}
+
+#[test]
+fn should_consider_unit_structs_always_close() {
+    expect(&Unit).to(be_close_to(Unit));
+}
+
+#[test]
+fn should_report_raw_identifiers_without_their_prefix() {
+    assert_eq!(
+        reason(Keywords { r#type: 1.0 }, Keywords { r#type: 2.0 }),
+        Some(String::from(
+            "at .type (was 1.0, expected 2.0): delta was 1.0, but absolute tolerance is 0.1"
+        ))
+    );
+}
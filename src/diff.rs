@@ -0,0 +1,577 @@
+//! Diffs between the [`Debug`] renderings of expected and actual values, used to explain mismatches
+//! that would otherwise be hard to spot.
+//!
+//! [`Debug`]: https://doc.rust-lang.org/std/fmt/trait.Debug.html
+
+use std::io::IsTerminal;
+
+/// Values whose compact renderings are shorter than this are easy enough to compare by eye.
+const MIN_DIFF_LENGTH: usize = 40;
+
+/// Unchanged lines shown around each change in a line diff.
+const CONTEXT_LINES: usize = 3;
+
+/// Bytes shown on each row of a hexdump diff.
+const HEXDUMP_WIDTH: usize = 8;
+
+/// Line diffs are skipped above this many `expected × actual` lines, to keep them cheap.
+const MAX_DIFF_CELLS: usize = 1_000_000;
+
+/// Differing rows shown in a hexdump diff, beyond which the rest are only counted.
+const MAX_HEXDUMP_CHANGES: usize = 32;
+
+/// Entries shown in each section of a map or set report, beyond which the rest are only counted.
+const MAX_REPORTED_ENTRIES: usize = 20;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const BOLD_RED: &str = "\x1b[1;31m";
+const BOLD_GREEN: &str = "\x1b[1;32m";
+const RESET: &str = "\x1b[0m";
+
+/// Returns a diff between the compact renderings of an expected and an actual value, or `None` if
+/// they are small enough to be compared without one, or identical, as with `NaN` or a custom
+/// `PartialEq`.
+///
+/// String literals spanning several lines get a unified line diff and other renderings get the
+/// differing characters highlighted.
+pub(crate) fn diff(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual || (expected.len() < MIN_DIFF_LENGTH && actual.len() < MIN_DIFF_LENGTH) {
+        return None;
+    }
+
+    render(expected, actual, use_colour())
+}
+
+/// Returns a side-by-side hexdump of two byte buffers, as explained by [`hexdump_diff`].
+pub(crate) fn hexdump(expected: &[u8], actual: &[u8]) -> String {
+    hexdump_diff(expected, actual, use_colour())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum CollectionKind {
+    Map,
+    Set,
+}
+
+/// The rendered entries of two maps or sets that differ: the keys only in one of them, with their
+/// values for maps, and the keys whose values differ, with both values.
+#[derive(Default)]
+pub(crate) struct CollectionDiff {
+    pub(crate) only_expected: Vec<(String, Option<String>)>,
+    pub(crate) only_actual: Vec<(String, Option<String>)>,
+    pub(crate) different: Vec<(String, String, String)>,
+}
+
+impl CollectionDiff {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.only_expected.is_empty() && self.only_actual.is_empty() && self.different.is_empty()
+    }
+}
+
+/// Reports the entries of two maps or sets that differ, as explained by [`collection_report`].
+pub(crate) fn collection_diff(kind: CollectionKind, diff: CollectionDiff) -> String {
+    collection_report(kind, diff, use_colour())
+}
+
+/// Reports the entries of two maps or sets that differ. Entries are sorted by key, comparing
+/// integers by value and anything else by its rendering, so that hash-based collections always
+/// give the same report. Past [`MAX_REPORTED_ENTRIES`] in a section, the rest are only counted.
+fn collection_report(kind: CollectionKind, mut diff: CollectionDiff, colour: bool) -> String {
+    let render = |(key, value): &(String, Option<String>)| match value {
+        Some(value) => format!("{}: {}", key, value),
+        None => key.to_string(),
+    };
+    diff.only_expected
+        .sort_by(|(a, _), (b, _)| compare_keys(a, b));
+    diff.only_actual
+        .sort_by(|(a, _), (b, _)| compare_keys(a, b));
+    diff.different
+        .sort_by(|(a, _, _), (b, _, _)| compare_keys(a, b));
+
+    let only_expected: Vec<String> = diff
+        .only_expected
+        .iter()
+        .map(|entry| paint(&format!("  {}", render(entry)), RED, colour))
+        .collect();
+    let only_actual: Vec<String> = diff
+        .only_actual
+        .iter()
+        .map(|entry| paint(&format!("  {}", render(entry)), GREEN, colour))
+        .collect();
+    let different: Vec<String> = diff
+        .different
+        .iter()
+        .map(|(key, expected, actual)| {
+            format!(
+                "  {}:\n{}{}",
+                key,
+                paint(&format!("    - {}", expected), RED, colour),
+                paint(&format!("    + {}", actual), GREEN, colour),
+            )
+        })
+        .collect();
+
+    let (name, items) = match kind {
+        CollectionKind::Map => ("map", "keys"),
+        CollectionKind::Set => ("set", "elements"),
+    };
+    let mut output = format!("{} diff (- expected, + actual):\n", name);
+    for (title, lines) in [
+        (format!("{} only in expected:", items), only_expected),
+        (format!("{} only in actual:", items), only_actual),
+        (format!("{} with different values:", items), different),
+    ] {
+        if !lines.is_empty() {
+            output.push_str(&format!("{}\n", title));
+            output.push_str(&lines[..lines.len().min(MAX_REPORTED_ENTRIES)].concat());
+            if lines.len() > MAX_REPORTED_ENTRIES {
+                output.push_str(&format!(
+                    "  ... and {} more\n",
+                    lines.len() - MAX_REPORTED_ENTRIES
+                ));
+            }
+        }
+    }
+    output
+}
+
+fn compare_keys(a: &str, b: &str) -> std::cmp::Ordering {
+    match (a.parse::<i128>(), b.parse::<i128>()) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+fn use_colour() -> bool {
+    let no_colour = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    !no_colour && std::io::stderr().is_terminal()
+}
+
+fn render(expected: &str, actual: &str, colour: bool) -> Option<String> {
+    let expected_lines = split_lines(expected);
+    let actual_lines = split_lines(actual);
+
+    if expected_lines.len() > 1 || actual_lines.len() > 1 {
+        line_diff(&expected_lines, &actual_lines, colour)
+    } else {
+        Some(char_diff(expected, actual, colour))
+    }
+}
+
+/// Splits a rendering into lines. String literals are kept on one line by `Debug`, so they are
+/// split after each escaped newline instead.
+fn split_lines(rendering: &str) -> Vec<&str> {
+    let is_string_literal =
+        rendering.len() > 1 && rendering.starts_with('"') && rendering.ends_with('"');
+    if is_string_literal && !rendering.contains('\n') {
+        rendering.split_inclusive("\\n").collect()
+    } else {
+        rendering.lines().collect()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+fn line_diff(expected: &[&str], actual: &[&str], colour: bool) -> Option<String> {
+    if expected.len() * actual.len() > MAX_DIFF_CELLS {
+        return None;
+    }
+
+    let lines = diff_lines(expected, actual);
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Line::Same(_)))
+        .map(|(i, _)| i)
+        .collect();
+
+    let near = near_changes(&changed, lines.len());
+    let mut output = String::from("diff (- expected, + actual):\n");
+    let mut last_shown: Option<usize> = None;
+    for (i, line) in lines.iter().enumerate() {
+        if !near[i] {
+            continue;
+        }
+        if last_shown.map_or(i > 0, |last| last + 1 < i) {
+            output.push_str("  ...\n");
+        }
+        last_shown = Some(i);
+
+        match line {
+            Line::Same(text) => output.push_str(&format!("  {}\n", text)),
+            Line::Removed(text) => output.push_str(&paint(&format!("- {}", text), RED, colour)),
+            Line::Added(text) => output.push_str(&paint(&format!("+ {}", text), GREEN, colour)),
+        }
+    }
+    Some(output)
+}
+
+/// Flags the lines, out of `len`, that are at most [`CONTEXT_LINES`] away from a changed one.
+fn near_changes(changed: &[usize], len: usize) -> Vec<bool> {
+    let mut near = vec![false; len];
+    for &c in changed {
+        let end = (c + CONTEXT_LINES).min(len.saturating_sub(1));
+        for flag in &mut near[c.saturating_sub(CONTEXT_LINES)..=end] {
+            *flag = true;
+        }
+    }
+    near
+}
+
+/// Renders two byte buffers as side-by-side hexdumps, showing the rows that differ and a few
+/// around them. Bytes are compared by offset, so an insertion shows up as a change to every
+/// following row: past [`MAX_HEXDUMP_CHANGES`] differing rows, the rest are only counted.
+fn hexdump_diff(expected: &[u8], actual: &[u8], colour: bool) -> String {
+    let rows = expected.len().max(actual.len()).div_ceil(HEXDUMP_WIDTH);
+    let row = |bytes: &[u8], i: usize| -> Vec<u8> {
+        bytes
+            .iter()
+            .skip(i * HEXDUMP_WIDTH)
+            .take(HEXDUMP_WIDTH)
+            .copied()
+            .collect()
+    };
+    let changed: Vec<usize> = (0..rows)
+        .filter(|&i| row(expected, i) != row(actual, i))
+        .collect();
+    let shown = &changed[..changed.len().min(MAX_HEXDUMP_CHANGES)];
+    let near = near_changes(shown, rows);
+
+    let mut output = String::from("hexdump diff (expected | actual):\n");
+    let mut last_shown: Option<usize> = None;
+    for i in (0..rows).filter(|&i| near[i]) {
+        if last_shown.map_or(i > 0, |last| last + 1 < i) {
+            output.push_str("  ...\n");
+        }
+        last_shown = Some(i);
+
+        let (expected_row, actual_row) = (row(expected, i), row(actual, i));
+        let (expected_text, expected_carets) =
+            hexdump_row(&expected_row, &actual_row, BOLD_RED, colour);
+        let (actual_text, actual_carets) =
+            hexdump_row(&actual_row, &expected_row, BOLD_GREEN, colour);
+        let text = format!(
+            "  {:08x}  {} | {}",
+            i * HEXDUMP_WIDTH,
+            expected_text,
+            actual_text
+        );
+        output.push_str(&format!("{}\n", text.trim_end()));
+        if !colour && expected_row != actual_row {
+            let carets = format!("  {:8}  {}   {}", "", expected_carets, actual_carets);
+            output.push_str(&format!("{}\n", carets.trim_end()));
+        }
+    }
+
+    let hidden = changed
+        .iter()
+        .filter(|&&i| last_shown.is_none_or(|last| i > last))
+        .count();
+    if hidden > 0 {
+        output.push_str(&format!("  ... {} more rows differ\n", hidden));
+    }
+    output
+}
+
+/// Renders a hexdump row as hex and ASCII columns, highlighting the bytes that differ from `other`
+/// either with `code` or, without colour, with a line of carets returned alongside.
+fn hexdump_row(bytes: &[u8], other: &[u8], code: &str, colour: bool) -> (String, String) {
+    let mut hex = Vec::new();
+    let mut hex_carets = Vec::new();
+    let mut ascii = String::new();
+    let mut ascii_carets = String::new();
+    for i in 0..HEXDUMP_WIDTH {
+        let (cell, character) = match bytes.get(i) {
+            Some(&byte) => (
+                format!("{:02x}", byte),
+                if byte.is_ascii_graphic() || byte == b' ' {
+                    byte as char
+                } else {
+                    '.'
+                },
+            ),
+            None => (String::from("  "), ' '),
+        };
+        let differs = bytes.get(i).is_some() && bytes.get(i) != other.get(i);
+        if differs && colour {
+            hex.push(format!("{}{}{}", code, cell, RESET));
+            ascii.push_str(&format!("{}{}{}", code, character, RESET));
+        } else {
+            hex.push(cell);
+            ascii.push(character);
+        }
+        hex_carets.push(if differs { "^^" } else { "  " });
+        ascii_carets.push(if differs { '^' } else { ' ' });
+    }
+    (
+        format!("{}  {}", hex.join(" "), ascii),
+        format!("{}  {}", hex_carets.join(" "), ascii_carets),
+    )
+}
+
+/// Computes a line diff through the longest common subsequence of `expected` and `actual`.
+fn diff_lines<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<Line<'a>> {
+    let (n, m) = (expected.len(), actual.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if expected[i] == actual[j] {
+            lines.push(Line::Same(expected[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(Line::Removed(expected[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(actual[j]));
+            j += 1;
+        }
+    }
+    lines.extend(expected[i..].iter().map(|line| Line::Removed(line)));
+    lines.extend(actual[j..].iter().map(|line| Line::Added(line)));
+    lines
+}
+
+/// Highlights the characters between the common prefix and the common suffix of two single-line
+/// renderings.
+fn char_diff(expected: &str, actual: &str, colour: bool) -> String {
+    let expected_chars: Vec<char> = expected.chars().collect();
+    let actual_chars: Vec<char> = actual.chars().collect();
+
+    let prefix = expected_chars
+        .iter()
+        .zip(&actual_chars)
+        .take_while(|(e, a)| e == a)
+        .count();
+    let suffix = expected_chars[prefix..]
+        .iter()
+        .rev()
+        .zip(actual_chars[prefix..].iter().rev())
+        .take_while(|(e, a)| e == a)
+        .count();
+
+    let split = |chars: &[char]| {
+        let end = chars.len() - suffix;
+        (
+            chars[..prefix].iter().collect::<String>(),
+            chars[prefix..end].iter().collect::<String>(),
+            chars[end..].iter().collect::<String>(),
+        )
+    };
+    let (e_prefix, e_middle, e_suffix) = split(&expected_chars);
+    let (a_prefix, a_middle, a_suffix) = split(&actual_chars);
+
+    let mut output = String::from("diff (- expected, + actual):\n");
+    if colour {
+        output.push_str(&format!(
+            "- {}{}{}{}{}\n",
+            e_prefix, BOLD_RED, e_middle, RESET, e_suffix
+        ));
+        output.push_str(&format!(
+            "+ {}{}{}{}{}\n",
+            a_prefix, BOLD_GREEN, a_middle, RESET, a_suffix
+        ));
+    } else {
+        let width = e_middle
+            .chars()
+            .count()
+            .max(a_middle.chars().count())
+            .max(1);
+        output.push_str(&format!("- {}\n", expected));
+        output.push_str(&format!("+ {}\n", actual));
+        output.push_str(&format!("  {}{}\n", " ".repeat(prefix), "^".repeat(width)));
+    }
+    output
+}
+
+fn paint(text: &str, code: &str, colour: bool) -> String {
+    if colour {
+        format!("{}{}{}\n", code, text, RESET)
+    } else {
+        format!("{}\n", text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        collection_report, diff, diff_lines, hexdump_diff, render, split_lines, CollectionDiff,
+        CollectionKind, Line,
+    };
+
+    #[test]
+    fn should_not_diff_small_values() {
+        assert_eq!(diff("[1, 2, 3]", "[1, 2, 4]"), None)
+    }
+
+    #[test]
+    fn should_not_diff_identical_renderings() {
+        let nans = format!("{:?}", vec![f64::NAN; 10]);
+        assert_eq!(diff(&nans, &nans), None)
+    }
+
+    #[test]
+    fn should_diff_large_values() {
+        let expected = format!("{:?}", (0..20).collect::<Vec<u32>>());
+        let actual = expected.replace(" 10,", " 42,");
+
+        let diff = diff(&expected, &actual).unwrap();
+        assert!(diff.contains(&format!("- {}\n", expected)));
+        assert!(diff.contains(&format!("+ {}\n", actual)));
+    }
+
+    #[test]
+    fn should_compute_line_diffs() {
+        assert_eq!(
+            diff_lines(&["a", "b", "c"], &["a", "c", "d"]),
+            vec![
+                Line::Same("a"),
+                Line::Removed("b"),
+                Line::Same("c"),
+                Line::Added("d"),
+            ]
+        )
+    }
+
+    #[test]
+    fn should_only_show_lines_around_changes() {
+        let expected: Vec<String> = (0..20).map(|i| i.to_string()).collect();
+        let mut actual = expected.clone();
+        actual[15] = String::from("x");
+        let expected: Vec<&str> = expected.iter().map(|s| s.as_str()).collect();
+        let actual: Vec<&str> = actual.iter().map(|s| s.as_str()).collect();
+
+        let diff = render(&expected.join("\n"), &actual.join("\n"), false).unwrap();
+        assert_eq!(
+            diff,
+            "diff (- expected, + actual):\n  ...\n  12\n  13\n  14\n- 15\n+ x\n  16\n  17\n  18\n"
+        )
+    }
+
+    #[test]
+    fn should_split_string_literals_on_escaped_newlines() {
+        assert_eq!(
+            split_lines("\"foo\\nbar\\nbaz\""),
+            vec!["\"foo\\n", "bar\\n", "baz\""]
+        )
+    }
+
+    #[test]
+    fn should_highlight_differing_characters_with_carets() {
+        assert_eq!(
+            render("\"foo bar baz\"", "\"foo bax baz\"", false).unwrap(),
+            "diff (- expected, + actual):\n- \"foo bar baz\"\n+ \"foo bax baz\"\n         ^\n"
+        )
+    }
+
+    #[test]
+    fn should_highlight_differing_characters_with_colours() {
+        assert_eq!(
+            render("\"foo bar\"", "\"foo baz\"", true).unwrap(),
+            "diff (- expected, + actual):\n- \"foo ba\x1b[1;31mr\x1b[0m\"\n+ \"foo ba\x1b[1;32mz\x1b[0m\"\n"
+        )
+    }
+
+    #[test]
+    fn should_colour_line_diffs() {
+        assert_eq!(
+            render("a\nb", "a\nc", true).unwrap(),
+            "diff (- expected, + actual):\n  a\n\x1b[31m- b\x1b[0m\n\x1b[32m+ c\x1b[0m\n"
+        )
+    }
+
+    #[test]
+    fn should_mark_differing_bytes_with_carets() {
+        assert_eq!(
+            hexdump_diff(b"abcdefghij", b"abcdefghi", false),
+            "hexdump diff (expected | actual):\n\
+             \x20 00000000  61 62 63 64 65 66 67 68  abcdefgh | 61 62 63 64 65 66 67 68  abcdefgh\n\
+             \x20 00000008  69 6a                    ij       | 69                       i\n\
+             \x20              ^^                     ^\n"
+        )
+    }
+
+    #[test]
+    fn should_only_show_hexdump_rows_around_changes() {
+        let expected = vec![0u8; 80];
+        let mut actual = expected.clone();
+        actual[72] = 1;
+
+        let diff = hexdump_diff(&expected, &actual, false);
+        assert!(diff.starts_with("hexdump diff (expected | actual):\n  ...\n  00000030"));
+    }
+
+    #[test]
+    fn should_only_count_hexdump_rows_past_the_first_changes() {
+        let expected = vec![0u8; 8 * 100];
+        let actual = vec![1u8; 8 * 100];
+
+        let diff = hexdump_diff(&expected, &actual, false);
+        assert!(diff.contains("\n  00000110  "));
+        assert!(!diff.contains("\n  00000118  "));
+        assert!(diff.ends_with("\n  ... 65 more rows differ\n"));
+    }
+
+    #[test]
+    fn should_report_differing_map_entries() {
+        let diff = CollectionDiff {
+            only_expected: vec![(String::from("10"), Some(String::from("\"ten\"")))],
+            only_actual: vec![(String::from("4"), Some(String::from("\"four\"")))],
+            different: vec![(
+                String::from("3"),
+                String::from("\"three\""),
+                String::from("\"tres\""),
+            )],
+        };
+        assert_eq!(
+            collection_report(CollectionKind::Map, diff, false),
+            "map diff (- expected, + actual):\n\
+             keys only in expected:\n  10: \"ten\"\n\
+             keys only in actual:\n  4: \"four\"\n\
+             keys with different values:\n  3:\n    - \"three\"\n    + \"tres\"\n"
+        )
+    }
+
+    #[test]
+    fn should_report_differing_set_elements_sorted() {
+        let diff = CollectionDiff {
+            only_expected: vec![(String::from("10"), None), (String::from("9"), None)],
+            only_actual: vec![(String::from("\"c\""), None)],
+            different: Vec::new(),
+        };
+        assert_eq!(
+            collection_report(CollectionKind::Set, diff, false),
+            "set diff (- expected, + actual):\n\
+             elements only in expected:\n  9\n  10\n\
+             elements only in actual:\n  \"c\"\n"
+        )
+    }
+
+    #[test]
+    fn should_only_count_entries_past_the_first_ones() {
+        let diff = CollectionDiff {
+            only_expected: (0..25).map(|i| (i.to_string(), None)).collect(),
+            ..CollectionDiff::default()
+        };
+        let report = collection_report(CollectionKind::Set, diff, false);
+        assert!(report.contains("\n  19\n  ... and 5 more\n"));
+        assert!(!report.contains("\n  20\n"));
+    }
+}
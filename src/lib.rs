@@ -19,8 +19,16 @@
 //!
 //! [matchers]: matchers/index.html
 //! [`Matcher`]: trait.Matcher.html
+mod diff;
 pub mod matchers;
+mod polling;
+mod soft;
 
+use matchers::logical::{AndMatcher, OrMatcher};
+pub use polling::{consistently, eventually, Consistently, Eventually};
+pub use soft::{soft, SoftExpectation, SoftExpectations};
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Description {
     pub verb: String,
     pub object: Option<String>,
@@ -44,13 +52,156 @@ pub trait Matcher<T> {
     /// assert_eq!(equal(2).description(&2).object, Some(String::from("2")));
     /// ```
     fn description(&self, actual: &T) -> Description;
+
+    /// Like [`match_value`], but can also explain _why_ `actual` is not a match.
+    ///
+    /// The default implementation delegates to [`match_value`] and doesn't provide any reason.
+    /// Matchers that can say something specific about `actual` should override it.
+    ///
+    /// [`match_value`]: #tymethod.match_value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use expect::{Matcher, MatchResult, matchers::result::be_ok};
+    /// assert_eq!(be_ok().match_result(&Ok::<u32, &str>(42)), MatchResult::Matched);
+    /// assert_eq!(
+    ///     be_ok().match_result(&Err::<u32, &str>("boom")),
+    ///     MatchResult::Mismatched { reason: Some(String::from("was an Err")) }
+    /// );
+    /// ```
+    fn match_result(&self, actual: &T) -> MatchResult {
+        MatchResult::from(self.match_value(actual))
+    }
+
+    /// Combines this matcher with `other` into a matcher that matches only if both match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use expect::{expect, Matcher, matchers::collection::contain};
+    /// expect(&vec![1, 2, 3]).to(contain(1).and(contain(2)));
+    /// ```
+    fn and<M: Matcher<T>>(self, other: M) -> AndMatcher<Self, M, T>
+    where
+        Self: Sized,
+    {
+        AndMatcher::new(self, other)
+    }
+
+    /// Combines this matcher with `other` into a matcher that matches if either matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use expect::{expect, Matcher, matchers::collection::{be_empty, contain}};
+    /// expect(&vec![1, 2, 3]).to(be_empty().or(contain(2)));
+    /// ```
+    fn or<M: Matcher<T>>(self, other: M) -> OrMatcher<Self, M, T>
+    where
+        Self: Sized,
+    {
+        OrMatcher::new(self, other)
+    }
+}
+
+impl<T, M: Matcher<T> + ?Sized> Matcher<T> for &M {
+    fn match_value(&self, actual: &T) -> bool {
+        (**self).match_value(actual)
+    }
+
+    fn description(&self, actual: &T) -> Description {
+        (**self).description(actual)
+    }
+
+    fn match_result(&self, actual: &T) -> MatchResult {
+        (**self).match_result(actual)
+    }
+}
+
+/// The result of matching a value against a [`Matcher`], as returned by
+/// [`Matcher::match_result`].
+///
+/// [`Matcher`]: trait.Matcher.html
+/// [`Matcher::match_result`]: trait.Matcher.html#method.match_result
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchResult {
+    Matched,
+    Mismatched { reason: Option<String> },
+}
+
+impl MatchResult {
+    /// Creates a `Mismatched` result with the given reason.
+    pub fn mismatched<S: Into<String>>(reason: S) -> Self {
+        MatchResult::Mismatched {
+            reason: Some(reason.into()),
+        }
+    }
+
+    /// Returns `true` if the result is `Matched`.
+    pub fn is_match(&self) -> bool {
+        matches!(self, MatchResult::Matched)
+    }
+}
+
+impl From<bool> for MatchResult {
+    fn from(matched: bool) -> Self {
+        if matched {
+            MatchResult::Matched
+        } else {
+            MatchResult::Mismatched { reason: None }
+        }
+    }
 }
 
 /// Creates an [`Expectation`].
 ///
 /// [`Expectation`]: struct.Expectation.html
-pub fn expect<T>(actual: &T) -> Expectation<T> {
-    Expectation { actual }
+pub fn expect<T>(actual: &T) -> Expectation<'_, T> {
+    Expectation {
+        actual,
+        source: None,
+    }
+}
+
+/// Creates an [`Expectation`] that knows where its actual value comes from.
+///
+/// `expect!(expr)` borrows `expr` like `expect(&expr)` does, but also captures the source of the
+/// expression and its location, so that failure messages can show them.
+///
+/// [`Expectation`]: struct.Expectation.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::equal};
+/// let name = "Giuseppe";
+/// expect!(name).to(equal("Giuseppe"));
+///
+/// let failure = expect!(name.len()).try_to(equal(3)).unwrap_err();
+/// assert!(failure.to_string().contains("Expected `name.len()` (at src/lib.rs:"));
+/// ```
+#[macro_export]
+macro_rules! expect {
+    ($actual:expr) => {
+        $crate::expect(&$actual).with_source(&$crate::Source {
+            expression: stringify!($actual),
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        })
+    };
+}
+
+/// The source code an actual value comes from, as captured by the [`expect!`] macro.
+///
+/// [`expect!`]: macro.expect.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct Source {
+    pub expression: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+    pub column: u32,
 }
 
 /// An expectation. It wraps a value so that it can be checked against a [`Matcher`].
@@ -58,21 +209,37 @@ pub fn expect<T>(actual: &T) -> Expectation<T> {
 /// [`Matcher`]: trait.Matcher.html
 pub struct Expectation<'a, T> {
     actual: &'a T,
+    source: Option<&'static Source>,
+}
+
+impl<'a, T> Expectation<'a, T> {
+    /// Attaches a [`Source`] to the expectation, to be shown in failure messages. This is usually
+    /// done through the [`expect!`] macro.
+    ///
+    /// [`Source`]: struct.Source.html
+    /// [`expect!`]: macro.expect.html
+    pub fn with_source(self, source: &'static Source) -> Self {
+        Expectation {
+            source: Some(source),
+            ..self
+        }
+    }
 }
 
 impl<'a, T: std::fmt::Debug> Expectation<'a, T> {
     /// Checks the actual value agains a [`Matcher`], looking for a match.
     ///
-    /// If [`Matcher::match_value`] returns `false`, this method will [`panic!`] with a failure
-    /// message based on the [`Matcher::description`].
+    /// If [`Matcher::match_result`] is a mismatch, this method will [`panic!`] with a failure
+    /// message based on the [`Matcher::description`] and the reason of the mismatch, if any.
     ///
     /// [`Matcher`]: trait.Matcher.html
-    /// [`Matcher::match_value`]: trait.Matcher.html#tymethod.match_value
+    /// [`Matcher::match_result`]: trait.Matcher.html#method.match_result
     /// [`Matcher::description`]: trait.Matcher.html#tymethod.description
     /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic.html
+    #[track_caller]
     pub fn to<M: Matcher<T>>(&self, matcher: M) {
-        if !matcher.match_value(&self.actual) {
-            fail_test(&self.actual, matcher.description(&self.actual))
+        if let Err(failure) = self.try_to(matcher) {
+            fail_test(failure)
         }
     }
 
@@ -85,42 +252,174 @@ impl<'a, T: std::fmt::Debug> Expectation<'a, T> {
     /// [`Matcher::match_value`]: trait.Matcher.html#tymethod.match_value
     /// [`Matcher::description`]: trait.Matcher.html#tymethod.description
     /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic.html
+    #[track_caller]
     pub fn not_to<M: Matcher<T>>(&self, matcher: M) {
-        if matcher.match_value(&self.actual) {
-            fail_test_negated(&self.actual, matcher.description(&self.actual))
+        if let Err(failure) = self.try_not_to(matcher) {
+            fail_test(failure)
+        }
+    }
+
+    /// Like [`to`], but returns an [`ExpectationFailure`] instead of panicking.
+    ///
+    /// [`to`]: #method.to
+    /// [`ExpectationFailure`]: struct.ExpectationFailure.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use expect::{expect, matchers::equal};
+    /// assert!(expect(&(2 + 2)).try_to(equal(4)).is_ok());
+    ///
+    /// let failure = expect(&(2 + 2)).try_to(equal(5)).unwrap_err();
+    /// assert_eq!(failure.actual, String::from("4"));
+    /// assert_eq!(failure.description.verb, String::from("equal"));
+    /// assert!(!failure.negated);
+    /// ```
+    pub fn try_to<M: Matcher<T>>(&self, matcher: M) -> Result<(), ExpectationFailure> {
+        match matcher.match_result(self.actual) {
+            MatchResult::Matched => Ok(()),
+            MatchResult::Mismatched { reason } => {
+                Err(self.failure(matcher.description(self.actual), false, reason))
+            }
+        }
+    }
+
+    /// Like [`not_to`], but returns an [`ExpectationFailure`] instead of panicking.
+    ///
+    /// [`not_to`]: #method.not_to
+    /// [`ExpectationFailure`]: struct.ExpectationFailure.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use expect::{expect, matchers::equal};
+    /// assert!(expect(&(2 + 2)).try_not_to(equal(5)).is_ok());
+    /// assert!(expect(&(2 + 2)).try_not_to(equal(4)).unwrap_err().negated);
+    /// ```
+    pub fn try_not_to<M: Matcher<T>>(&self, matcher: M) -> Result<(), ExpectationFailure> {
+        if matcher.match_value(self.actual) {
+            Err(self.failure(matcher.description(self.actual), true, None))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn failure(
+        &self,
+        description: Description,
+        negated: bool,
+        reason: Option<String>,
+    ) -> ExpectationFailure {
+        let actual = format!("{:?}", self.actual);
+        // `equal` can't render the actual value itself, as it doesn't require it to be `Debug`, so
+        // its diff is computed here, where the rendering is at hand.
+        let reason = match (&description.object, reason) {
+            (Some(expected), None) if !negated && description.verb == matchers::EQUAL_VERB => {
+                diff::diff(expected, &actual)
+            }
+            (_, reason) => reason,
+        };
+        ExpectationFailure {
+            actual,
+            description,
+            negated,
+            reason,
+            source: self.source,
         }
     }
 }
 
-fn fail_test<T: std::fmt::Debug>(actual: T, description: Description) {
-    panic!("{}", failure_message(actual, description, "to"))
+/// The outcome of a failed [`Expectation`], as returned by [`Expectation::try_to`] and
+/// [`Expectation::try_not_to`]. Its [`Display`] implementation renders the same message
+/// [`Expectation::to`] and [`Expectation::not_to`] panic with.
+///
+/// [`Expectation`]: struct.Expectation.html
+/// [`Expectation::try_to`]: struct.Expectation.html#method.try_to
+/// [`Expectation::try_not_to`]: struct.Expectation.html#method.try_not_to
+/// [`Expectation::to`]: struct.Expectation.html#method.to
+/// [`Expectation::not_to`]: struct.Expectation.html#method.not_to
+/// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpectationFailure {
+    /// The [`Debug`] rendering of the actual value.
+    ///
+    /// [`Debug`]: https://doc.rust-lang.org/std/fmt/trait.Debug.html
+    pub actual: String,
+    /// The [`Description`] of the matcher that failed.
+    ///
+    /// [`Description`]: struct.Description.html
+    pub description: Description,
+    /// `true` if the failure comes from a negated expectation (`not_to`).
+    pub negated: bool,
+    /// Why the actual value didn't match, as explained by [`Matcher::match_result`].
+    ///
+    /// [`Matcher::match_result`]: trait.Matcher.html#method.match_result
+    pub reason: Option<String>,
+    /// Where the actual value comes from, if the expectation was created through [`expect!`].
+    ///
+    /// [`expect!`]: macro.expect.html
+    pub source: Option<&'static Source>,
 }
 
-fn fail_test_negated<T: std::fmt::Debug>(actual: T, description: Description) {
-    panic!("{}", failure_message(actual, description, "not to"))
+impl std::fmt::Display for ExpectationFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let before_verb = if self.negated { "not to" } else { "to" };
+        f.write_str(&failure_message(
+            &self.actual,
+            &self.description,
+            before_verb,
+            self.reason.as_deref(),
+            self.source,
+        ))
+    }
 }
 
-fn failure_message<T: std::fmt::Debug>(
-    actual: T,
-    description: Description,
+impl std::error::Error for ExpectationFailure {}
+
+#[track_caller]
+fn fail_test(failure: ExpectationFailure) {
+    panic!("{}", failure)
+}
+
+fn failure_message(
+    actual: &str,
+    description: &Description,
     before_verb: &str,
+    reason: Option<&str>,
+    source: Option<&Source>,
 ) -> String {
-    let predicate = if let Some(obj) = description.object {
+    let predicate = if let Some(obj) = &description.object {
         format!("{}:\n\t\t{}", description.verb, obj)
     } else {
-        description.verb
+        description.verb.clone()
+    };
+
+    let subject = if let Some(source) = source {
+        format!(
+            "Expected `{}` (at {}:{})",
+            source.expression, source.file, source.line
+        )
+    } else {
+        String::from("Expected")
     };
 
-    format!(
-        "Expectation failed:\n\tExpected:\n\t\t{:?}\n\t{} {}\n",
-        actual, before_verb, predicate
-    )
+    let mut message = format!(
+        "Expectation failed:\n\t{}:\n\t\t{}\n\t{} {}\n",
+        subject, actual, before_verb, predicate
+    );
+    if let Some(reason) = reason {
+        message.push_str("\tbut:\n");
+        for line in reason.lines() {
+            message.push_str(&format!("\t\t{}\n", line));
+        }
+    }
+    message
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::expect;
-    use crate::matchers::equal;
+    use crate::matchers::{equal, result::be_ok};
+    use crate::{expect, Description, ExpectationFailure, MatchResult, Source};
 
     #[test]
     fn expect_to_should_not_panic_if_the_matcher_matches_successfully() {
@@ -143,4 +442,119 @@ mod tests {
     fn expect_not_to_should_not_panic_if_the_matcher_fails_to_match() {
         expect(&(2 + 2)).not_to(equal(5))
     }
+
+    #[test]
+    fn expect_try_to_should_return_ok_if_the_matcher_matches_successfully() {
+        assert_eq!(expect(&(2 + 2)).try_to(equal(4)), Ok(()))
+    }
+
+    #[test]
+    fn expect_try_to_should_explain_large_mismatches_of_equal_with_a_diff() {
+        let expected = "the quick brown fox\njumps over\nthe lazy dog";
+        let actual = "the quick brown fox\njumps over\nthe lazy cat";
+
+        let reason = expect(&actual).try_to(equal(expected)).unwrap_err().reason;
+        let reason = reason.expect("expected a mismatch with a diff");
+        assert!(reason.contains("- the lazy dog\"\n"));
+        assert!(reason.contains("+ the lazy cat\"\n"));
+    }
+
+    #[test]
+    fn expect_try_to_should_not_diff_identical_renderings() {
+        let nans = vec![f64::NAN; 10];
+        assert_eq!(
+            expect(&nans)
+                .try_to(equal(nans.clone()))
+                .unwrap_err()
+                .reason,
+            None
+        )
+    }
+
+    #[test]
+    fn expect_try_to_should_return_a_failure_if_the_matcher_fails_to_match() {
+        assert_eq!(
+            expect(&(2 + 2)).try_to(equal(5)),
+            Err(ExpectationFailure {
+                actual: String::from("4"),
+                description: Description {
+                    verb: String::from("equal"),
+                    object: Some(String::from("5")),
+                },
+                negated: false,
+                reason: None,
+                source: None,
+            })
+        )
+    }
+
+    #[test]
+    fn expect_try_not_to_should_return_a_failure_if_the_matcher_matches_successfully() {
+        let failure = expect(&(2 + 2)).try_not_to(equal(4)).unwrap_err();
+        assert!(failure.negated)
+    }
+
+    #[test]
+    fn expect_try_not_to_should_return_ok_if_the_matcher_fails_to_match() {
+        assert_eq!(expect(&(2 + 2)).try_not_to(equal(5)), Ok(()))
+    }
+
+    #[test]
+    fn expectation_failures_should_display_the_failure_message() {
+        let failure = expect(&(2 + 2)).try_not_to(equal(4)).unwrap_err();
+        assert_eq!(
+            failure.to_string(),
+            "Expectation failed:\n\tExpected:\n\t\t4\n\tnot to equal:\n\t\t4\n"
+        )
+    }
+
+    #[test]
+    fn expectation_failures_should_include_the_mismatch_reason() {
+        let failure = expect(&Err::<u32, &str>("boom"))
+            .try_to(be_ok())
+            .unwrap_err();
+        assert_eq!(failure.reason, Some(String::from("was an Err")));
+        assert!(failure
+            .to_string()
+            .ends_with("\tto be Ok\n\tbut:\n\t\twas an Err\n"))
+    }
+
+    #[test]
+    fn match_results_can_be_built_from_booleans() {
+        assert_eq!(MatchResult::from(true), MatchResult::Matched);
+        assert_eq!(
+            MatchResult::from(false),
+            MatchResult::Mismatched { reason: None }
+        );
+    }
+
+    #[test]
+    fn expect_macro_should_capture_the_source_of_the_actual_value() {
+        let numbers = [1, 2, 3];
+        let failure = expect!(numbers.len()).try_to(equal(4)).unwrap_err();
+        assert_eq!(
+            failure.source,
+            Some(&Source {
+                expression: "numbers.len()",
+                file: file!(),
+                line: line!() - 6,
+                column: 23,
+            })
+        );
+        assert!(failure.to_string().starts_with(&format!(
+            "Expectation failed:\n\tExpected `numbers.len()` (at {}:{}):\n\t\t3\n",
+            file!(),
+            line!() - 13
+        )))
+    }
+
+    #[test]
+    fn expect_to_should_report_the_source_location_when_panicking() {
+        let line = line!() + 1;
+        let result = std::panic::catch_unwind(|| expect!(2 + 2).to(equal(5)));
+
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.contains(&format!("Expected `2 + 2` (at {}:{})", file!(), line)));
+    }
 }
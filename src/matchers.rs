@@ -1,13 +1,37 @@
+pub mod bytes;
 pub mod collection;
+pub mod float;
+pub mod logical;
+pub mod map;
+pub mod numeric;
 pub mod option;
+pub mod panic;
 pub mod path;
 pub mod result;
 pub mod string;
 
 use crate::{Description, Matcher};
 
+/// The verb describing [`equal`], whose failures get a diff from [`Expectation`].
+///
+/// [`equal`]: fn.equal.html
+/// [`Expectation`]: ../struct.Expectation.html
+pub(crate) const EQUAL_VERB: &str = "equal";
+
 /// Matches if `expected` is equal to the actual value.
 ///
+/// When the values are large, the failure of an expectation on `equal` will include a diff of their
+/// [`Debug`] renderings: a line diff for strings spanning several lines, or a highlight of the
+/// differing characters otherwise. The diff is coloured when printed to a terminal, unless
+/// `NO_COLOR` is set. Only the expected value needs to be `Debug`. Use [`equal_bytes`],
+/// [`equal_map`] or [`equal_set`] to compare byte buffers, maps or sets with a report suited to
+/// them instead.
+///
+/// [`Debug`]: https://doc.rust-lang.org/std/fmt/trait.Debug.html
+/// [`equal_bytes`]: bytes/fn.equal_bytes.html
+/// [`equal_map`]: map/fn.equal_map.html
+/// [`equal_set`]: collection/fn.equal_set.html
+///
 /// # Examples
 ///
 /// ```
@@ -30,7 +54,7 @@ impl<E: std::fmt::Debug, A: PartialEq<E>> Matcher<A> for EqualMatcher<E> {
 
     fn description(&self, _actual: &A) -> Description {
         Description {
-            verb: String::from("equal"),
+            verb: String::from(EQUAL_VERB),
             object: Some(format!("{:?}", self.expected)),
         }
     }
@@ -39,7 +63,7 @@ impl<E: std::fmt::Debug, A: PartialEq<E>> Matcher<A> for EqualMatcher<E> {
 #[cfg(test)]
 mod tests {
     use super::equal;
-    use crate::Matcher;
+    use crate::{MatchResult, Matcher};
 
     #[test]
     fn should_match_if_actual_equals_expected() {
@@ -62,4 +86,25 @@ mod tests {
         assert_eq!(description.verb, String::from("equal"));
         assert_eq!(description.object, Some(String::from("\"foo\"")));
     }
+
+    #[test]
+    fn should_not_explain_small_mismatches() {
+        assert_eq!(
+            equal("foo").match_result(&"bar"),
+            MatchResult::Mismatched { reason: None }
+        )
+    }
+
+    #[test]
+    fn should_accept_actual_values_without_debug() {
+        struct Opaque(u32);
+        impl PartialEq<u32> for Opaque {
+            fn eq(&self, other: &u32) -> bool {
+                self.0 == *other
+            }
+        }
+
+        assert!(equal(1).match_value(&Opaque(1)));
+        assert!(!equal(2).match_value(&Opaque(1)));
+    }
 }
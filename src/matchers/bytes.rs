@@ -0,0 +1,401 @@
+use crate::{diff::hexdump, Description, MatchResult, Matcher};
+
+use regex::bytes::Regex;
+
+/// How many of the actual bytes are shown when explaining a mismatch.
+const MAX_SHOWN_BYTES: usize = 64;
+
+/// How many bytes are shown before the first differing one.
+const CONTEXT_BYTES: usize = 16;
+
+/// Matches if the provided regular expression matches the actual bytes, which don't need to be
+/// valid UTF-8.
+///
+/// `regex` can be a pattern, as a `&str` or a `String`, or an already compiled
+/// [`regex::bytes::Regex`]. Patterns are compiled once, when the matcher is created. As with
+/// `regex::bytes`, Unicode mode is on by default: disable it with `(?-u)` for `.` and friends to
+/// match arbitrary bytes.
+///
+/// [`regex::bytes::Regex`]: https://docs.rs/regex/1/regex/bytes/struct.Regex.html
+///
+/// # Panics
+///
+/// Panics if `regex` is not a valid regular expression. Use [`try_match_bytes_regex`] to handle
+/// the error instead.
+///
+/// [`try_match_bytes_regex`]: fn.try_match_bytes_regex.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::bytes::match_bytes_regex};
+/// expect(&b"HTTP/1.1 200 OK\r\n\xff".to_vec()).to(match_bytes_regex(r"^HTTP/1\.\d 200"));
+/// expect(&b"\x00\x01".to_vec()).not_to(match_bytes_regex(r"^HTTP"));
+/// ```
+#[track_caller]
+pub fn match_bytes_regex<R: IntoBytesRegex>(regex: R) -> MatchBytesRegexMatcher {
+    match try_match_bytes_regex(regex) {
+        Ok(matcher) => matcher,
+        Err(error) => panic!("invalid regex passed to match_bytes_regex:\n{}", error),
+    }
+}
+
+/// Like [`match_bytes_regex`], but returns an error instead of panicking if `regex` is not a valid
+/// regular expression.
+///
+/// [`match_bytes_regex`]: fn.match_bytes_regex.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::bytes::try_match_bytes_regex};
+/// expect(&b"abc-123".to_vec()).to(try_match_bytes_regex(r"\d{3}").unwrap());
+/// assert!(try_match_bytes_regex(r"\d{3").is_err());
+/// ```
+pub fn try_match_bytes_regex<R: IntoBytesRegex>(
+    regex: R,
+) -> Result<MatchBytesRegexMatcher, regex::Error> {
+    Ok(MatchBytesRegexMatcher {
+        regex: regex.into_bytes_regex()?,
+    })
+}
+
+pub struct MatchBytesRegexMatcher {
+    regex: Regex,
+}
+
+impl<A: AsRef<[u8]>> Matcher<A> for MatchBytesRegexMatcher {
+    fn match_value(&self, actual: &A) -> bool {
+        self.regex.is_match(actual.as_ref())
+    }
+
+    fn match_result(&self, actual: &A) -> MatchResult {
+        explain(self.match_value(actual), actual.as_ref(), 0)
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("match regex"),
+            object: Some(format!("{:?}", self.regex.as_str())),
+        }
+    }
+}
+
+/// A value that can be turned into a [`regex::bytes::Regex`], as accepted by
+/// [`match_bytes_regex`]: either a pattern or an already compiled `Regex`.
+///
+/// [`regex::bytes::Regex`]: https://docs.rs/regex/1/regex/bytes/struct.Regex.html
+/// [`match_bytes_regex`]: fn.match_bytes_regex.html
+pub trait IntoBytesRegex {
+    fn into_bytes_regex(self) -> Result<Regex, regex::Error>;
+}
+
+impl IntoBytesRegex for Regex {
+    fn into_bytes_regex(self) -> Result<Regex, regex::Error> {
+        Ok(self)
+    }
+}
+
+impl IntoBytesRegex for &Regex {
+    fn into_bytes_regex(self) -> Result<Regex, regex::Error> {
+        Ok(self.clone())
+    }
+}
+
+impl IntoBytesRegex for &str {
+    fn into_bytes_regex(self) -> Result<Regex, regex::Error> {
+        Regex::new(self)
+    }
+}
+
+impl IntoBytesRegex for String {
+    fn into_bytes_regex(self) -> Result<Regex, regex::Error> {
+        Regex::new(&self)
+    }
+}
+
+impl IntoBytesRegex for &String {
+    fn into_bytes_regex(self) -> Result<Regex, regex::Error> {
+        Regex::new(self)
+    }
+}
+
+/// Matches if the actual bytes start with `prefix`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::bytes::start_with_bytes};
+/// expect(&b"\x89PNG\r\n".to_vec()).to(start_with_bytes(b"\x89PNG"));
+/// expect(&b"GIF89a".to_vec()).not_to(start_with_bytes(b"\x89PNG"));
+/// ```
+pub fn start_with_bytes<B: AsRef<[u8]>>(prefix: B) -> StartWithBytesMatcher<B> {
+    StartWithBytesMatcher { prefix }
+}
+
+pub struct StartWithBytesMatcher<B> {
+    prefix: B,
+}
+
+impl<A: AsRef<[u8]>, E: AsRef<[u8]>> Matcher<A> for StartWithBytesMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        actual.as_ref().starts_with(self.prefix.as_ref())
+    }
+
+    fn match_result(&self, actual: &A) -> MatchResult {
+        let (actual, prefix) = (actual.as_ref(), self.prefix.as_ref());
+        let mismatch = actual
+            .iter()
+            .zip(prefix)
+            .position(|(a, p)| a != p)
+            .unwrap_or_else(|| actual.len().min(prefix.len()));
+        explain(actual.starts_with(prefix), actual, mismatch)
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("start with bytes"),
+            object: Some(escape(self.prefix.as_ref())),
+        }
+    }
+}
+
+/// Matches if the actual bytes contain `bytes` as a contiguous sequence.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::bytes::contain_bytes};
+/// expect(&b"\x00\x01\x02\x03".to_vec()).to(contain_bytes([1, 2]));
+/// expect(&b"\x00\x01\x02\x03".to_vec()).not_to(contain_bytes([2, 1]));
+/// ```
+pub fn contain_bytes<B: AsRef<[u8]>>(bytes: B) -> ContainBytesMatcher<B> {
+    ContainBytesMatcher { bytes }
+}
+
+pub struct ContainBytesMatcher<B> {
+    bytes: B,
+}
+
+impl<A: AsRef<[u8]>, E: AsRef<[u8]>> Matcher<A> for ContainBytesMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        let needle = self.bytes.as_ref();
+        needle.is_empty()
+            || actual
+                .as_ref()
+                .windows(needle.len())
+                .any(|window| window == needle)
+    }
+
+    fn match_result(&self, actual: &A) -> MatchResult {
+        explain(self.match_value(actual), actual.as_ref(), 0)
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("contain bytes"),
+            object: Some(escape(self.bytes.as_ref())),
+        }
+    }
+}
+
+/// Matches if the actual bytes are equal to `expected`.
+///
+/// Unlike [`equal`], which compares the values themselves, this compares any two byte buffers, such
+/// as a `Vec<u8>` and a byte string, and explains a mismatch with a side-by-side hexdump of the
+/// rows that differ. The hexdump is coloured when printed to a terminal, unless `NO_COLOR` is set.
+///
+/// [`equal`]: ../fn.equal.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::bytes::equal_bytes};
+/// expect(&b"GET / HTTP/1.1\r\n".to_vec()).to(equal_bytes(b"GET / HTTP/1.1\r\n"));
+/// expect(&vec![0u8, 1, 2]).not_to(equal_bytes([0, 1]));
+/// ```
+pub fn equal_bytes<B: AsRef<[u8]>>(expected: B) -> EqualBytesMatcher<B> {
+    EqualBytesMatcher { expected }
+}
+
+pub struct EqualBytesMatcher<B> {
+    expected: B,
+}
+
+impl<A: AsRef<[u8]>, E: AsRef<[u8]>> Matcher<A> for EqualBytesMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        actual.as_ref() == self.expected.as_ref()
+    }
+
+    fn match_result(&self, actual: &A) -> MatchResult {
+        if self.match_value(actual) {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(hexdump(self.expected.as_ref(), actual.as_ref()))
+        }
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("equal bytes"),
+            object: Some(escape(self.expected.as_ref())),
+        }
+    }
+}
+
+/// Byte buffers are rendered by `Debug` as lists of numbers, so mismatches also show them as a
+/// byte string. Past [`MAX_SHOWN_BYTES`], only the bytes around `at`, where the mismatch was found,
+/// are shown.
+fn explain(matched: bool, actual: &[u8], at: usize) -> MatchResult {
+    if matched {
+        MatchResult::Matched
+    } else if actual.len() <= MAX_SHOWN_BYTES {
+        MatchResult::mismatched(format!("was {}", escape(actual)))
+    } else {
+        let end = (at.saturating_sub(CONTEXT_BYTES) + MAX_SHOWN_BYTES).min(actual.len());
+        let start = end - MAX_SHOWN_BYTES;
+        MatchResult::mismatched(format!(
+            "was {}{}{} (bytes {}..{} of {})",
+            if start > 0 { "..." } else { "" },
+            escape(&actual[start..end]),
+            if end < actual.len() { "..." } else { "" },
+            start,
+            end,
+            actual.len()
+        ))
+    }
+}
+
+/// Renders bytes as a byte string literal, e.g. `b"GET /\r\n"`.
+fn escape(bytes: &[u8]) -> String {
+    let escaped: String = bytes
+        .iter()
+        .flat_map(|&byte| std::ascii::escape_default(byte))
+        .map(char::from)
+        .collect();
+    format!("b\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        contain_bytes, equal_bytes, match_bytes_regex, start_with_bytes, try_match_bytes_regex,
+    };
+    use crate::{MatchResult, Matcher};
+
+    #[test]
+    fn match_bytes_regex_matcher_should_match_invalid_utf8() {
+        assert!(match_bytes_regex(r"^a(?-u:.)b$").match_value(&b"a\xffb".to_vec()));
+        assert!(!match_bytes_regex(r"^ab$").match_value(&b"a\xffb"));
+    }
+
+    #[test]
+    fn match_bytes_regex_matcher_should_explain_a_mismatch() {
+        assert_eq!(
+            match_bytes_regex(r"^ab$").match_result(&b"a\xffb\"".to_vec()),
+            MatchResult::mismatched("was b\"a\\xffb\\\"\"")
+        )
+    }
+
+    #[test]
+    fn match_bytes_regex_matcher_should_accept_compiled_regexes() {
+        let regex = regex::bytes::Regex::new("^a").unwrap();
+        assert!(match_bytes_regex(&regex).match_value(&b"abc"));
+        assert!(try_match_bytes_regex("a(").is_err());
+    }
+
+    #[test]
+    fn match_bytes_regex_matcher_should_describe_itself() {
+        let description = match_bytes_regex("foo").description(&b"bar");
+        assert_eq!(description.verb, String::from("match regex"));
+        assert_eq!(description.object, Some(String::from("\"foo\"")));
+    }
+
+    #[test]
+    fn start_with_bytes_matcher_should_match_if_actual_starts_with_prefix() {
+        assert!(start_with_bytes(b"\x89P").match_value(&b"\x89PNG".to_vec()));
+        assert!(!start_with_bytes([0u8]).match_value(&b"\x89PNG".to_vec()));
+    }
+
+    #[test]
+    fn start_with_bytes_matcher_should_describe_itself() {
+        let description = start_with_bytes(b"\x89PNG").description(&b"");
+        assert_eq!(description.verb, String::from("start with bytes"));
+        assert_eq!(description.object, Some(String::from("b\"\\x89PNG\"")));
+    }
+
+    #[test]
+    fn contain_bytes_matcher_should_match_contiguous_bytes() {
+        assert!(contain_bytes([1, 2]).match_value(&vec![0u8, 1, 2, 3]));
+        assert!(contain_bytes([0u8; 0]).match_value(&vec![0u8]));
+        assert!(!contain_bytes([1, 3]).match_value(&vec![0u8, 1, 2, 3]));
+    }
+
+    #[test]
+    fn contain_bytes_matcher_should_describe_itself() {
+        let description = contain_bytes(vec![0u8, 10]).description(&b"");
+        assert_eq!(description.verb, String::from("contain bytes"));
+        assert_eq!(description.object, Some(String::from("b\"\\x00\\n\"")));
+    }
+
+    #[test]
+    fn equal_bytes_matcher_should_compare_any_byte_buffers() {
+        let buffer = vec![1u8, 2];
+        assert!(equal_bytes([1, 2]).match_value(&buffer));
+        assert!(equal_bytes(&buffer[..]).match_value(&&buffer[..]));
+        assert!(equal_bytes(b"\x01\x02").match_value(&buffer.clone().into_boxed_slice()));
+        assert!(!equal_bytes([1, 2, 3]).match_value(&buffer));
+    }
+
+    #[test]
+    fn equal_bytes_matcher_should_explain_a_mismatch_with_a_hexdump() {
+        let expected = b"GET / HTTP/1.1\r\nHost: example.com\r\n";
+        let actual = b"GET / HTTP/1.0\r\nHost: example.com\r\n".to_vec();
+
+        if let MatchResult::Mismatched {
+            reason: Some(reason),
+        } = equal_bytes(expected).match_result(&actual)
+        {
+            assert!(reason.starts_with("hexdump diff (expected | actual):\n"));
+            assert!(reason.contains(
+                "  00000008  54 50 2f 31 2e 31 0d 0a  TP/1.1.. | 54 50 2f 31 2e 30 0d 0a  TP/1.0..\n"
+            ));
+        } else {
+            panic!("expected a mismatch with a hexdump")
+        }
+    }
+
+    #[test]
+    fn equal_bytes_matcher_should_describe_itself() {
+        let description = equal_bytes(b"\x89PNG").description(&b"");
+        assert_eq!(description.verb, String::from("equal bytes"));
+        assert_eq!(description.object, Some(String::from("b\"\\x89PNG\"")));
+    }
+
+    #[test]
+    fn start_with_bytes_matcher_should_only_show_the_bytes_around_the_mismatch() {
+        let mut actual = vec![b'a'; 1000];
+        actual[500] = b'b';
+        let expected = vec![b'a'; 600];
+
+        assert_eq!(
+            start_with_bytes(expected).match_result(&actual),
+            MatchResult::mismatched(format!(
+                "was ...b\"{}b{}\"... (bytes 484..548 of 1000)",
+                "a".repeat(16),
+                "a".repeat(47)
+            ))
+        )
+    }
+
+    #[test]
+    fn contain_bytes_matcher_should_only_show_the_first_bytes_of_a_large_buffer() {
+        assert_eq!(
+            contain_bytes([1]).match_result(&vec![0u8; 100]),
+            MatchResult::mismatched(format!(
+                "was b\"{}\"... (bytes 0..64 of 100)",
+                "\\x00".repeat(64)
+            ))
+        )
+    }
+}
@@ -1,10 +1,21 @@
-use crate::{Description, Matcher};
+use crate::diff::{collection_diff, CollectionDiff, CollectionKind};
+use crate::matchers::logical::{phrase, MatcherList};
+use crate::matchers::{equal, EqualMatcher};
+use crate::{Description, MatchResult, Matcher};
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// How many elements are listed when explaining a mismatch of [`all_elements`] and the like.
+///
+/// [`all_elements`]: fn.all_elements.html
+const MAX_LISTED_ELEMENTS: usize = 10;
 
 /// Matches if `actual` contains `element`.
 ///
-/// Supports [arrays] [`Vec`]s, [`VecDeque`]s, [`LinkedList`]s, [`HashSet`]s and [`BTreeSet`]s.
+/// Supports [arrays], [`Vec`]s, [`VecDeque`]s, [`LinkedList`]s, [`HashSet`]s and [`BTreeSet`]s.
 ///
-/// [array]: https://doc.rust-lang.org/std/primitive.array.html
+/// [arrays]: https://doc.rust-lang.org/std/primitive.array.html
 /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
 /// [`VecDeque`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html
 /// [`LinkedList`]: https://doc.rust-lang.org/std/collections/struct.LinkedList.html
@@ -31,6 +42,14 @@ impl<T: std::fmt::Debug, V: Container<T>> Matcher<V> for ContainMatcher<T> {
         collection.contains_element(&self.element)
     }
 
+    fn match_result(&self, collection: &V) -> MatchResult {
+        if self.match_value(collection) {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(format!("element {:?} was missing", self.element))
+        }
+    }
+
     fn description(&self, _: &V) -> Description {
         Description {
             verb: String::from("contain"),
@@ -81,14 +100,17 @@ impl<T: std::cmp::Ord> Container<T> for std::collections::BTreeSet<T> {
 
 /// Matches if `actual` is empty.
 ///
-/// Supports [arrays], [`Vec`]s, [`VecDeque`]s, [`LinkedList`]s, [`HashSet`]s and [`BTreeSet`]s.
+/// Supports [arrays], [`Vec`]s, [`VecDeque`]s, [`LinkedList`]s, [`HashSet`]s, [`BTreeSet`]s,
+/// [`HashMap`]s and [`BTreeMap`]s.
 ///
-/// [array]: https://doc.rust-lang.org/std/primitive.array.html
+/// [arrays]: https://doc.rust-lang.org/std/primitive.array.html
 /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
 /// [`VecDeque`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html
 /// [`LinkedList`]: https://doc.rust-lang.org/std/collections/struct.LinkedList.html
 /// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
 /// [`BTreeSet`]: https://doc.rust-lang.org/std/collections/struct.BTreeSet.html
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
 ///
 /// Examples
 ///
@@ -155,146 +177,2511 @@ impl<T> Emptiable for std::collections::BTreeSet<T> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{be_empty, contain, Container, Emptiable};
-    use crate::Matcher;
+impl<K, V> Emptiable for std::collections::HashMap<K, V> {
+    fn empty(&self) -> bool {
+        self.is_empty()
+    }
+}
 
-    #[test]
-    fn contain_matcher_should_match_if_collection_contains_element() {
-        assert!(contain("foo").match_value(&vec!["foo"]))
+impl<K, V> Emptiable for std::collections::BTreeMap<K, V> {
+    fn empty(&self) -> bool {
+        self.is_empty()
     }
+}
 
-    #[test]
-    fn contain_matcher_should_not_match_if_collection_does_not_contain_element() {
-        assert!(!contain("foo").match_value(&vec!["bar"]))
+/// Matches if the length of `actual` is `len`, or matches `len` if it's a matcher.
+///
+/// Supports [arrays], [`Vec`]s, [`VecDeque`]s, [`LinkedList`]s, [`HashSet`]s, [`BTreeSet`]s,
+/// [`HashMap`]s, [`BTreeMap`]s, slices and strings. The length of a string is its length in
+/// bytes, as returned by [`str::len`].
+///
+/// [arrays]: https://doc.rust-lang.org/std/primitive.array.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`VecDeque`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html
+/// [`LinkedList`]: https://doc.rust-lang.org/std/collections/struct.LinkedList.html
+/// [`HashSet`]: https://doc.rust-lang.org/std/collections/struct.HashSet.html
+/// [`BTreeSet`]: https://doc.rust-lang.org/std/collections/struct.BTreeSet.html
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+/// [`str::len`]: https://doc.rust-lang.org/std/primitive.str.html#method.len
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{collection::have_len, numeric::be_greater_than}};
+/// expect(&vec![1, 2, 3]).to(have_len(3));
+/// expect(&"foo").to(have_len(be_greater_than(2)));
+/// ```
+pub fn have_len<L: IntoLenMatcher>(len: L) -> HaveLenMatcher<L::Matcher> {
+    HaveLenMatcher {
+        inner: len.into_len_matcher(),
     }
+}
 
-    #[test]
-    fn contain_matcher_should_describe_itself() {
-        let description = contain("foo").description(&vec!["bar"]);
-        assert_eq!(description.verb, String::from("contain"));
-        assert_eq!(description.object, Some(String::from("\"foo\"")));
+pub struct HaveLenMatcher<M> {
+    inner: M,
+}
+
+impl<V: HaveLen, M: Matcher<usize>> Matcher<V> for HaveLenMatcher<M> {
+    fn match_value(&self, collection: &V) -> bool {
+        self.inner.match_value(&collection.length())
     }
 
-    #[test]
-    fn be_empty_matcher_should_match_if_collection_is_empty() {
-        assert!(be_empty().match_value(&std::vec::Vec::<i32>::new()))
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let length = collection.length();
+        match self.inner.match_result(&length) {
+            MatchResult::Matched => MatchResult::Matched,
+            MatchResult::Mismatched { reason } => {
+                let was = format!("length was {}", length);
+                MatchResult::mismatched(match reason {
+                    Some(reason) => format!("{}\n{}", was, reason),
+                    None => was,
+                })
+            }
+        }
     }
 
-    #[test]
-    fn be_empty_matcher_should_not_match_if_collection_is_not_empty() {
-        assert!(!be_empty().match_value(&vec![42]))
+    fn description(&self, collection: &V) -> Description {
+        let inner_desc = self.inner.description(&collection.length());
+        Description {
+            verb: format!("have a length to {}", inner_desc.verb),
+            object: inner_desc.object,
+        }
     }
+}
 
-    #[test]
-    fn be_empty_matcher_should_describe_itself() {
-        let description = be_empty().description(&vec!["foo"]);
-        assert_eq!(description.verb, String::from("be empty"));
-        assert_eq!(description.object, None);
+/// A length, or a matcher for it, as accepted by [`have_len`].
+///
+/// [`have_len`]: fn.have_len.html
+pub trait IntoLenMatcher {
+    type Matcher: Matcher<usize>;
+
+    fn into_len_matcher(self) -> Self::Matcher;
+}
+
+impl IntoLenMatcher for usize {
+    type Matcher = EqualMatcher<usize>;
+
+    fn into_len_matcher(self) -> Self::Matcher {
+        equal(self)
     }
+}
 
-    #[test]
-    fn arrays_are_containers() {
-        assert!([1, 2, 3].contains_element(&2));
-        assert!(![1, 2, 3].contains_element(&4));
+impl<M: Matcher<usize>> IntoLenMatcher for M {
+    type Matcher = M;
+
+    fn into_len_matcher(self) -> Self::Matcher {
+        self
     }
+}
 
-    #[test]
-    fn arrays_are_emptiable() {
-        assert!(([] as [u64; 0]).empty());
-        assert!(![1, 2, 3].empty());
+pub trait HaveLen {
+    fn length(&self) -> usize;
+}
+
+impl<T: HaveLen + ?Sized> HaveLen for &T {
+    fn length(&self) -> usize {
+        (**self).length()
     }
+}
 
-    #[test]
-    fn vecs_are_containers() {
-        assert!(vec![1, 2, 3].contains_element(&2));
-        assert!(!vec![1, 2, 3].contains_element(&4));
+impl<T, const N: usize> HaveLen for [T; N] {
+    fn length(&self) -> usize {
+        self.len()
     }
+}
 
-    #[test]
-    fn vecs_are_emptiable() {
-        assert!(std::vec::Vec::<i32>::new().empty());
-        assert!(!vec![1, 2, 3].empty());
+impl<T> HaveLen for [T] {
+    fn length(&self) -> usize {
+        self.len()
     }
+}
 
-    #[test]
-    fn vecdeques_are_containers() {
-        let mut numbers = std::collections::VecDeque::new();
-        numbers.push_back(1);
-        numbers.push_back(2);
-        numbers.push_back(3);
+impl<T> HaveLen for std::vec::Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
 
-        assert!(numbers.contains_element(&2));
-        assert!(!numbers.contains_element(&4));
+impl<T> HaveLen for std::collections::VecDeque<T> {
+    fn length(&self) -> usize {
+        self.len()
     }
+}
 
-    #[test]
-    fn vecdeques_are_emptiable() {
-        let mut numbers = std::collections::VecDeque::new();
-        assert!(numbers.empty());
+impl<T> HaveLen for std::collections::LinkedList<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
 
-        numbers.push_back(42);
-        assert!(!numbers.empty());
+impl<T> HaveLen for std::collections::HashSet<T> {
+    fn length(&self) -> usize {
+        self.len()
     }
+}
 
-    #[test]
-    fn linkedlists_are_containers() {
-        let mut numbers = std::collections::LinkedList::new();
-        numbers.push_back(1);
-        numbers.push_back(2);
-        numbers.push_back(3);
+impl<T> HaveLen for std::collections::BTreeSet<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
 
-        assert!(numbers.contains_element(&2));
-        assert!(!numbers.contains_element(&4));
+impl<K, V> HaveLen for std::collections::HashMap<K, V> {
+    fn length(&self) -> usize {
+        self.len()
     }
+}
 
-    #[test]
-    fn linkedlists_are_emptiable() {
-        let mut numbers = std::collections::LinkedList::new();
-        assert!(numbers.empty());
+impl<K, V> HaveLen for std::collections::BTreeMap<K, V> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
 
-        numbers.push_back(42);
-        assert!(!numbers.empty())
+impl HaveLen for str {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl HaveLen for String {
+    fn length(&self) -> usize {
+        self.len()
     }
+}
 
-    #[test]
-    fn hashsets_are_containers() {
-        let mut numbers = std::collections::HashSet::new();
-        numbers.insert(1);
-        numbers.insert(2);
-        numbers.insert(3);
+/// Matches if `actual` contains exactly `elements`, in any order.
+///
+/// Each element has to appear as many times as it does in `elements`. Supports the same
+/// collections as [`contain`].
+///
+/// [`contain`]: fn.contain.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::contain_exactly};
+/// expect(&vec![3, 1, 2]).to(contain_exactly([1, 2, 3]));
+/// expect(&vec![1, 1, 2]).not_to(contain_exactly([1, 2, 2]));
+/// ```
+pub fn contain_exactly<T, I: IntoIterator<Item = T>>(elements: I) -> ContainExactlyMatcher<T> {
+    ContainExactlyMatcher {
+        elements: elements.into_iter().collect(),
+    }
+}
 
-        assert!(numbers.contains_element(&2));
-        assert!(!numbers.contains_element(&4));
+pub struct ContainExactlyMatcher<T> {
+    elements: Vec<T>,
+}
+
+impl<T: PartialEq + std::fmt::Debug, V: Collection<Item = T>> Matcher<V>
+    for ContainExactlyMatcher<T>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        self.match_result(collection).is_match()
     }
 
-    #[test]
-    fn hashsets_are_emptiable() {
-        let mut numbers = std::collections::HashSet::new();
-        assert!(numbers.empty());
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let actual = collection.elements();
+        let (missing, unexpected) = pair_up(&self.elements, &actual, |e, a| e == *a);
+        explain_pairing(
+            missing
+                .iter()
+                .map(|&i| format!("{:?}", self.elements[i]))
+                .collect(),
+            "missing elements",
+            &unexpected.iter().map(|&i| actual[i]).collect::<Vec<_>>(),
+        )
+    }
 
-        numbers.insert(42);
-        assert!(!numbers.empty())
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("contain exactly"),
+            object: Some(format!("{:?}", self.elements)),
+        }
     }
+}
 
-    #[test]
-    fn btreesets_are_containers() {
-        let mut numbers = std::collections::BTreeSet::new();
-        numbers.insert(1);
-        numbers.insert(2);
-        numbers.insert(3);
+/// Matches if the elements of `actual` can be paired up with `matchers`, so that every element
+/// matches its own matcher.
+///
+/// `matchers` can be a tuple of up to 8 matchers, possibly of different types, or an array of
+/// matchers of the same type. Supports the same collections as [`contain`].
+///
+/// [`contain`]: fn.contain.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{collection::consist_of, equal, numeric::be_greater_than}};
+/// expect(&vec![5, 1]).to(consist_of((equal(1), be_greater_than(3))));
+/// expect(&vec![5, 1]).not_to(consist_of([be_greater_than(3), be_greater_than(3)]));
+/// ```
+pub fn consist_of<L>(matchers: L) -> ConsistOfMatcher<L> {
+    ConsistOfMatcher { matchers }
+}
 
-        assert!(numbers.contains_element(&2));
-        assert!(!numbers.contains_element(&4));
+pub struct ConsistOfMatcher<L> {
+    matchers: L,
+}
+
+impl<T: std::fmt::Debug, V: Collection<Item = T>, L: MatcherList<T>> Matcher<V>
+    for ConsistOfMatcher<L>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        self.match_result(collection).is_match()
     }
 
-    #[test]
-    fn btreesets_are_emptiable() {
-        let mut numbers = std::collections::BTreeSet::new();
-        assert!(numbers.empty());
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let matchers = self.matchers.matchers();
+        let actual = collection.elements();
+        // Matchers can only be described with a value at hand, so an empty collection can't say
+        // which ones were left alone.
+        let first = match actual.first() {
+            Some(first) => *first,
+            None if matchers.is_empty() => return MatchResult::Matched,
+            None => return MatchResult::mismatched("was empty"),
+        };
+        let (missing, unexpected) = pair_up(&matchers, &actual, |m, a| m.match_value(a));
+        explain_pairing(
+            missing
+                .iter()
+                .map(|&i| phrase(matchers[i].description(first)))
+                .collect(),
+            "no element matched",
+            &unexpected.iter().map(|&i| actual[i]).collect::<Vec<_>>(),
+        )
+    }
 
-        numbers.insert(42);
-        assert!(!numbers.empty())
+    fn description(&self, collection: &V) -> Description {
+        let matchers = self.matchers.matchers();
+        let object = match collection.elements().first() {
+            Some(first) => matchers
+                .iter()
+                .map(|matcher| phrase(matcher.description(first)))
+                .collect::<Vec<_>>()
+                .join(", "),
+            None => format!("{} matchers", matchers.len()),
+        };
+        Description {
+            verb: String::from("consist of elements to"),
+            object: Some(object),
+        }
+    }
+}
+
+/// Matches if `actual` contains all of `elements`.
+///
+/// Supports the same collections as [`contain`].
+///
+/// [`contain`]: fn.contain.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::contain_all};
+/// expect(&vec![1, 2, 3]).to(contain_all([3, 1]));
+/// expect(&vec![1, 2, 3]).not_to(contain_all([1, 4]));
+/// ```
+pub fn contain_all<T, I: IntoIterator<Item = T>>(elements: I) -> ContainAllMatcher<T> {
+    ContainAllMatcher {
+        elements: elements.into_iter().collect(),
+    }
+}
+
+pub struct ContainAllMatcher<T> {
+    elements: Vec<T>,
+}
+
+impl<T: std::fmt::Debug, V: Container<T>> Matcher<V> for ContainAllMatcher<T> {
+    fn match_value(&self, collection: &V) -> bool {
+        self.elements
+            .iter()
+            .all(|element| collection.contains_element(element))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let missing: Vec<String> = self
+            .elements
+            .iter()
+            .filter(|element| !collection.contains_element(element))
+            .map(|element| format!("{:?}", element))
+            .collect();
+        if missing.is_empty() {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(format!("missing elements: {}", missing.join(", ")))
+        }
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("contain all of"),
+            object: Some(format!("{:?}", self.elements)),
+        }
+    }
+}
+
+/// Matches if `actual` contains at least one of `elements`.
+///
+/// Supports the same collections as [`contain`].
+///
+/// [`contain`]: fn.contain.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::contain_any};
+/// expect(&vec![1, 2, 3]).to(contain_any([4, 3]));
+/// expect(&vec![1, 2, 3]).not_to(contain_any([4, 5]));
+/// ```
+pub fn contain_any<T, I: IntoIterator<Item = T>>(elements: I) -> ContainAnyMatcher<T> {
+    ContainAnyMatcher {
+        elements: elements.into_iter().collect(),
+    }
+}
+
+pub struct ContainAnyMatcher<T> {
+    elements: Vec<T>,
+}
+
+impl<T: std::fmt::Debug, V: Container<T>> Matcher<V> for ContainAnyMatcher<T> {
+    fn match_value(&self, collection: &V) -> bool {
+        self.elements
+            .iter()
+            .any(|element| collection.contains_element(element))
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("contain any of"),
+            object: Some(format!("{:?}", self.elements)),
+        }
+    }
+}
+
+/// Matches if `elements` appear in `actual` in the same order, although not necessarily next to
+/// each other.
+///
+/// Supports ordered collections: [arrays], [`Vec`]s, [`VecDeque`]s, [`LinkedList`]s and slices.
+///
+/// [arrays]: https://doc.rust-lang.org/std/primitive.array.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`VecDeque`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html
+/// [`LinkedList`]: https://doc.rust-lang.org/std/collections/struct.LinkedList.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::contain_in_order};
+/// expect(&vec![1, 2, 3, 4]).to(contain_in_order([1, 3, 4]));
+/// expect(&vec![1, 2, 3, 4]).not_to(contain_in_order([3, 1]));
+/// ```
+pub fn contain_in_order<T, I: IntoIterator<Item = T>>(elements: I) -> ContainInOrderMatcher<T> {
+    ContainInOrderMatcher {
+        elements: elements.into_iter().collect(),
+    }
+}
+
+pub struct ContainInOrderMatcher<T> {
+    elements: Vec<T>,
+}
+
+impl<T: PartialEq + std::fmt::Debug, V: Sequence<Item = T>> Matcher<V>
+    for ContainInOrderMatcher<T>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        self.match_result(collection).is_match()
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let actual = collection.elements();
+        let mut found: Option<usize> = None;
+        for (i, element) in self.elements.iter().enumerate() {
+            let start = found.map_or(0, |index| index + 1);
+            match actual[start..].iter().position(|a| *a == element) {
+                Some(offset) => found = Some(start + offset),
+                None => {
+                    let reason = match found {
+                        Some(index) if actual.contains(&element) => format!(
+                            "element {:?} was not found after element {:?} at index {}",
+                            element,
+                            self.elements[i - 1],
+                            index
+                        ),
+                        _ => format!("element {:?} was missing", element),
+                    };
+                    return MatchResult::mismatched(reason);
+                }
+            }
+        }
+        MatchResult::Matched
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("contain in order"),
+            object: Some(format!("{:?}", self.elements)),
+        }
+    }
+}
+
+/// Matches if `elements` appear in `actual` next to each other and in the same order.
+///
+/// Supports the same collections as [`contain_in_order`].
+///
+/// [`contain_in_order`]: fn.contain_in_order.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::contain_slice};
+/// expect(&vec![1, 2, 3, 4]).to(contain_slice([2, 3]));
+/// expect(&vec![1, 2, 3, 4]).not_to(contain_slice([2, 4]));
+/// ```
+pub fn contain_slice<T, I: IntoIterator<Item = T>>(elements: I) -> ContainSliceMatcher<T> {
+    ContainSliceMatcher {
+        elements: elements.into_iter().collect(),
+    }
+}
+
+pub struct ContainSliceMatcher<T> {
+    elements: Vec<T>,
+}
+
+impl<T: PartialEq + std::fmt::Debug, V: Sequence<Item = T>> Matcher<V> for ContainSliceMatcher<T> {
+    fn match_value(&self, collection: &V) -> bool {
+        self.match_result(collection).is_match()
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let actual = collection.elements();
+        // The longest run of `elements` found, as its start and length.
+        let (start, len) = (0..actual.len())
+            .map(|start| {
+                let len = actual[start..]
+                    .iter()
+                    .zip(&self.elements)
+                    .take_while(|(a, e)| *a == e)
+                    .count();
+                (start, len)
+            })
+            .fold((0, 0), |best, run| if run.1 > best.1 { run } else { best });
+        if len == self.elements.len() {
+            return MatchResult::Matched;
+        }
+        if len == 0 {
+            return MatchResult::mismatched(format!("element {:?} was missing", self.elements[0]));
+        }
+        let run = format!("{:?} was found at index {}", &self.elements[..len], start);
+        MatchResult::mismatched(match actual.get(start + len) {
+            Some(next) => format!(
+                "{}, but followed by {:?} instead of {:?}",
+                run, next, self.elements[len]
+            ),
+            None => format!(
+                "{}, but the collection ended before {:?}",
+                run, self.elements[len]
+            ),
+        })
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("contain slice"),
+            object: Some(format!("{:?}", self.elements)),
+        }
+    }
+}
+
+/// Matches if every element of `actual` is also in `set`.
+///
+/// Both `actual` and `set` can be any of the collections supported by [`contain_exactly`], and are
+/// treated as sets, ignoring order and repetitions.
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::be_subset_of};
+/// # use std::collections::HashSet;
+/// expect(&HashSet::from(["read"])).to(be_subset_of(HashSet::from(["read", "write"])));
+/// expect(&vec!["read", "admin"]).not_to(be_subset_of(["read", "write"]));
+/// ```
+pub fn be_subset_of<S>(set: S) -> BeSubsetOfMatcher<S> {
+    BeSubsetOfMatcher { set }
+}
+
+pub struct BeSubsetOfMatcher<S> {
+    set: S,
+}
+
+impl<T: std::fmt::Debug, V: Collection<Item = T>, S: Container<T> + std::fmt::Debug> Matcher<V>
+    for BeSubsetOfMatcher<S>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        collection
+            .elements()
+            .iter()
+            .all(|element| self.set.contains_element(element))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let extra: Vec<&T> = collection
+            .elements()
+            .into_iter()
+            .filter(|element| !self.set.contains_element(element))
+            .collect();
+        if extra.is_empty() {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(format!("elements not in the set: {:?}", extra))
+        }
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("be a subset of"),
+            object: Some(format!("{:?}", self.set)),
+        }
+    }
+}
+
+/// Matches if every element of `set` is also in `actual`.
+///
+/// Both `actual` and `set` can be any of the collections supported by [`contain_exactly`], and are
+/// treated as sets, ignoring order and repetitions.
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::be_superset_of};
+/// # use std::collections::BTreeSet;
+/// expect(&BTreeSet::from(["read", "write"])).to(be_superset_of(["read"]));
+/// expect(&vec!["read"]).not_to(be_superset_of(["read", "write"]));
+/// ```
+pub fn be_superset_of<S>(set: S) -> BeSupersetOfMatcher<S> {
+    BeSupersetOfMatcher { set }
+}
+
+pub struct BeSupersetOfMatcher<S> {
+    set: S,
+}
+
+impl<T: std::fmt::Debug, V: Container<T>, S: Collection<Item = T> + std::fmt::Debug> Matcher<V>
+    for BeSupersetOfMatcher<S>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        self.set
+            .elements()
+            .iter()
+            .all(|element| collection.contains_element(element))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let missing: Vec<&T> = self
+            .set
+            .elements()
+            .into_iter()
+            .filter(|element| !collection.contains_element(element))
+            .collect();
+        if missing.is_empty() {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(format!("missing elements: {:?}", missing))
+        }
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("be a superset of"),
+            object: Some(format!("{:?}", self.set)),
+        }
+    }
+}
+
+/// Matches if `actual` and `set` have no element in common.
+///
+/// Both `actual` and `set` can be any of the collections supported by [`contain_exactly`].
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::be_disjoint_from};
+/// expect(&vec!["read"]).to(be_disjoint_from(["admin"]));
+/// expect(&vec!["read", "admin"]).not_to(be_disjoint_from(["admin"]));
+/// ```
+pub fn be_disjoint_from<S>(set: S) -> BeDisjointFromMatcher<S> {
+    BeDisjointFromMatcher { set }
+}
+
+pub struct BeDisjointFromMatcher<S> {
+    set: S,
+}
+
+impl<T: std::fmt::Debug, V: Container<T>, S: Collection<Item = T> + std::fmt::Debug> Matcher<V>
+    for BeDisjointFromMatcher<S>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        !self
+            .set
+            .elements()
+            .iter()
+            .any(|element| collection.contains_element(element))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let common: Vec<&T> = self
+            .set
+            .elements()
+            .into_iter()
+            .filter(|element| collection.contains_element(element))
+            .collect();
+        if common.is_empty() {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(format!("elements in common: {:?}", common))
+        }
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("be disjoint from"),
+            object: Some(format!("{:?}", self.set)),
+        }
+    }
+}
+
+/// Matches if `actual` and `set` have at least one element in common.
+///
+/// Both `actual` and `set` can be any of the collections supported by [`contain_exactly`].
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::intersect};
+/// expect(&vec!["read", "admin"]).to(intersect(["admin", "root"]));
+/// expect(&vec!["read"]).not_to(intersect(["admin", "root"]));
+/// ```
+pub fn intersect<S>(set: S) -> IntersectMatcher<S> {
+    IntersectMatcher { set }
+}
+
+pub struct IntersectMatcher<S> {
+    set: S,
+}
+
+impl<T: std::fmt::Debug, V: Container<T>, S: Collection<Item = T> + std::fmt::Debug> Matcher<V>
+    for IntersectMatcher<S>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        self.set
+            .elements()
+            .iter()
+            .any(|element| collection.contains_element(element))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        if self.match_value(collection) {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched("no elements in common")
+        }
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("intersect"),
+            object: Some(format!("{:?}", self.set)),
+        }
+    }
+}
+
+/// Matches if `actual` and `set` have the same elements.
+///
+/// Both `actual` and `set` can be any of the collections supported by [`contain_exactly`], and are
+/// treated as sets, ignoring order and repetitions. Unlike [`equal`], a failure reports the
+/// elements only in one of them, sorted, whatever the size of the sets.
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+/// [`equal`]: ../fn.equal.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::equal_set};
+/// # use std::collections::{BTreeSet, HashSet};
+/// expect(&HashSet::from(["read", "write"])).to(equal_set(HashSet::from(["write", "read"])));
+/// expect(&BTreeSet::from(["read"])).not_to(equal_set(BTreeSet::from(["read", "write"])));
+/// ```
+pub fn equal_set<S>(set: S) -> EqualSetMatcher<S> {
+    EqualSetMatcher { set }
+}
+
+pub struct EqualSetMatcher<S> {
+    set: S,
+}
+
+impl<T: std::fmt::Debug, V, S> Matcher<V> for EqualSetMatcher<S>
+where
+    V: Collection<Item = T> + Container<T>,
+    S: Collection<Item = T> + Container<T> + std::fmt::Debug,
+{
+    fn match_value(&self, collection: &V) -> bool {
+        self.set
+            .elements()
+            .iter()
+            .all(|element| collection.contains_element(element))
+            && collection
+                .elements()
+                .iter()
+                .all(|element| self.set.contains_element(element))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let only = |elements: Vec<&T>, other: &dyn Container<T>| {
+            elements
+                .into_iter()
+                .filter(|element| !other.contains_element(element))
+                .map(|element| (format!("{:?}", element), None))
+                .collect()
+        };
+        let diff = CollectionDiff {
+            only_expected: only(self.set.elements(), collection),
+            only_actual: only(collection.elements(), &self.set),
+            different: Vec::new(),
+        };
+        if diff.is_empty() {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(collection_diff(CollectionKind::Set, diff))
+        }
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("equal"),
+            object: Some(format!("{:?}", self.set)),
+        }
+    }
+}
+
+/// Matches if every element of `actual` matches `inner`.
+///
+/// Supports the same collections as [`contain_exactly`]. An empty collection always matches.
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{collection::all_elements, option::be_some}};
+/// expect(&vec![Some(1), Some(2)]).to(all_elements(be_some()));
+/// expect(&vec![Some(1), None]).not_to(all_elements(be_some()));
+/// ```
+pub fn all_elements<M>(inner: M) -> AllElementsMatcher<M> {
+    AllElementsMatcher { inner }
+}
+
+pub struct AllElementsMatcher<M> {
+    inner: M,
+}
+
+impl<T: std::fmt::Debug, V: Collection<Item = T>, M: Matcher<T>> Matcher<V>
+    for AllElementsMatcher<M>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        collection
+            .elements()
+            .iter()
+            .all(|element| self.inner.match_value(element))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let elements = collection.elements();
+        let mismatching = select_elements(&elements, &self.inner, false);
+        if mismatching.is_empty() {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(format!(
+                "mismatching elements:\n{}",
+                list_elements(&elements, &mismatching)
+            ))
+        }
+    }
+
+    fn description(&self, collection: &V) -> Description {
+        describe_elements(&self.inner, collection, "every element")
+    }
+}
+
+/// Matches if at least one element of `actual` matches `inner`.
+///
+/// Supports the same collections as [`contain_exactly`].
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{collection::any_element, numeric::be_greater_than}};
+/// expect(&vec![1, 5]).to(any_element(be_greater_than(3)));
+/// expect(&vec![1, 2]).not_to(any_element(be_greater_than(3)));
+/// ```
+pub fn any_element<M>(inner: M) -> AnyElementMatcher<M> {
+    AnyElementMatcher { inner }
+}
+
+pub struct AnyElementMatcher<M> {
+    inner: M,
+}
+
+impl<T: std::fmt::Debug, V: Collection<Item = T>, M: Matcher<T>> Matcher<V>
+    for AnyElementMatcher<M>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        collection
+            .elements()
+            .iter()
+            .any(|element| self.inner.match_value(element))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let elements = collection.elements();
+        if elements.is_empty() {
+            return MatchResult::mismatched("was empty");
+        }
+        let mismatching = select_elements(&elements, &self.inner, false);
+        if mismatching.len() < elements.len() {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(format!(
+                "mismatching elements:\n{}",
+                list_elements(&elements, &mismatching)
+            ))
+        }
+    }
+
+    fn description(&self, collection: &V) -> Description {
+        describe_elements(&self.inner, collection, "some element")
+    }
+}
+
+/// Matches if no element of `actual` matches `inner`.
+///
+/// Supports the same collections as [`contain_exactly`]. An empty collection always matches.
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{collection::no_element, option::be_none}};
+/// expect(&vec![Some(1), Some(2)]).to(no_element(be_none()));
+/// expect(&vec![Some(1), None]).not_to(no_element(be_none()));
+/// ```
+pub fn no_element<M>(inner: M) -> NoElementMatcher<M> {
+    NoElementMatcher { inner }
+}
+
+pub struct NoElementMatcher<M> {
+    inner: M,
+}
+
+impl<T: std::fmt::Debug, V: Collection<Item = T>, M: Matcher<T>> Matcher<V>
+    for NoElementMatcher<M>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        !collection
+            .elements()
+            .iter()
+            .any(|element| self.inner.match_value(element))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let elements = collection.elements();
+        let matching = select_elements(&elements, &self.inner, true);
+        if matching.is_empty() {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(format!(
+                "matching elements:\n{}",
+                list_elements(&elements, &matching)
+            ))
+        }
+    }
+
+    fn description(&self, collection: &V) -> Description {
+        describe_elements(&self.inner, collection, "no element")
+    }
+}
+
+/// Matches if exactly `n` elements of `actual` match `inner`.
+///
+/// Supports the same collections as [`contain_exactly`].
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{collection::exactly_n_elements, numeric::be_greater_than}};
+/// expect(&vec![1, 5, 7]).to(exactly_n_elements(2, be_greater_than(3)));
+/// expect(&vec![1, 5, 7]).not_to(exactly_n_elements(1, be_greater_than(3)));
+/// ```
+pub fn exactly_n_elements<M>(n: usize, inner: M) -> ExactlyNElementsMatcher<M> {
+    ExactlyNElementsMatcher { n, inner }
+}
+
+pub struct ExactlyNElementsMatcher<M> {
+    n: usize,
+    inner: M,
+}
+
+impl<T: std::fmt::Debug, V: Collection<Item = T>, M: Matcher<T>> Matcher<V>
+    for ExactlyNElementsMatcher<M>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        let matching = collection
+            .elements()
+            .iter()
+            .filter(|element| self.inner.match_value(element))
+            .count();
+        matching == self.n
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let elements = collection.elements();
+        let matching = select_elements(&elements, &self.inner, true);
+        if matching.len() == self.n {
+            MatchResult::Matched
+        } else if matching.is_empty() {
+            MatchResult::mismatched("no elements matched")
+        } else {
+            MatchResult::mismatched(format!(
+                "{} matching elements:\n{}",
+                matching.len(),
+                list_elements(&elements, &matching)
+            ))
+        }
+    }
+
+    fn description(&self, collection: &V) -> Description {
+        let quantifier = format!(
+            "exactly {} element{}",
+            self.n,
+            if self.n == 1 { "" } else { "s" }
+        );
+        describe_elements(&self.inner, collection, &quantifier)
+    }
+}
+
+/// Runs `inner` once on every element and keeps the indexes and results of those that match or
+/// not, depending on `matching`.
+fn select_elements<T, M: Matcher<T>>(
+    elements: &[&T],
+    inner: &M,
+    matching: bool,
+) -> Vec<(usize, MatchResult)> {
+    elements
+        .iter()
+        .enumerate()
+        .map(|(index, element)| (index, inner.match_result(element)))
+        .filter(|(_, result)| result.is_match() == matching)
+        .collect()
+}
+
+/// Lists the selected elements along with their indexes and, for mismatches, any single-line
+/// reason. Past [`MAX_LISTED_ELEMENTS`], the rest are only counted.
+fn list_elements<T: std::fmt::Debug>(elements: &[&T], selected: &[(usize, MatchResult)]) -> String {
+    let mut lines: Vec<String> = selected
+        .iter()
+        .take(MAX_LISTED_ELEMENTS)
+        .map(|(index, result)| {
+            let element = elements[*index];
+            match result {
+                MatchResult::Mismatched {
+                    reason: Some(reason),
+                } if !reason.contains('\n') => format!("[{}] {:?}: {}", index, element, reason),
+                _ => format!("[{}] {:?}", index, element),
+            }
+        })
+        .collect();
+    if selected.len() > MAX_LISTED_ELEMENTS {
+        lines.push(format!(
+            "... and {} more",
+            selected.len() - MAX_LISTED_ELEMENTS
+        ));
+    }
+    lines.join("\n")
+}
+
+fn describe_elements<T, V: Collection<Item = T>, M: Matcher<T>>(
+    inner: &M,
+    collection: &V,
+    quantifier: &str,
+) -> Description {
+    // The inner matcher can only be described with an element at hand.
+    match collection.elements().first() {
+        Some(first) => {
+            let inner_desc = inner.description(first);
+            Description {
+                verb: format!("have {} {}", quantifier, inner_desc.verb),
+                object: inner_desc.object,
+            }
+        }
+        None => Description {
+            verb: format!("have {} match the inner matcher", quantifier),
+            object: None,
+        },
+    }
+}
+
+/// Matches if the elements of `actual` are sorted in ascending order, or in descending order after
+/// calling [`descending`].
+///
+/// Supports the same collections as [`contain_in_order`]. Elements that can't be compared, like
+/// `NaN`s, are out of order.
+///
+/// [`descending`]: struct.BeSortedMatcher.html#method.descending
+/// [`contain_in_order`]: fn.contain_in_order.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::be_sorted};
+/// expect(&vec![1, 2, 2, 3]).to(be_sorted());
+/// expect(&vec![3, 2, 2, 1]).to(be_sorted().descending());
+/// expect(&vec![1, 3, 2]).not_to(be_sorted());
+/// ```
+pub fn be_sorted() -> BeSortedMatcher {
+    BeSortedMatcher { descending: false }
+}
+
+pub struct BeSortedMatcher {
+    descending: bool,
+}
+
+impl BeSortedMatcher {
+    /// Requires the elements to be sorted in descending order instead.
+    pub fn descending(mut self) -> Self {
+        self.descending = true;
+        self
+    }
+}
+
+impl<T: PartialOrd + std::fmt::Debug, V: Sequence<Item = T>> Matcher<V> for BeSortedMatcher {
+    fn match_value(&self, collection: &V) -> bool {
+        self.match_result(collection).is_match()
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        explain_order(&collection.elements(), |a, b| {
+            if self.descending {
+                a >= b
+            } else {
+                a <= b
+            }
+        })
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from(if self.descending {
+                "be sorted in descending order"
+            } else {
+                "be sorted"
+            }),
+            object: None,
+        }
+    }
+}
+
+/// Matches if the elements of `actual` are sorted according to `compare`, as they would be by
+/// [`slice::sort_by`].
+///
+/// Supports the same collections as [`contain_in_order`].
+///
+/// [`slice::sort_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by
+/// [`contain_in_order`]: fn.contain_in_order.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::be_sorted_by};
+/// expect(&vec!["A", "b", "C"]).to(be_sorted_by(|a: &&str, b: &&str| {
+///     a.to_lowercase().cmp(&b.to_lowercase())
+/// }));
+/// ```
+pub fn be_sorted_by<F>(compare: F) -> BeSortedByMatcher<F> {
+    BeSortedByMatcher { compare }
+}
+
+pub struct BeSortedByMatcher<F> {
+    compare: F,
+}
+
+impl<T: std::fmt::Debug, V: Sequence<Item = T>, F: Fn(&T, &T) -> std::cmp::Ordering> Matcher<V>
+    for BeSortedByMatcher<F>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        self.match_result(collection).is_match()
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        explain_order(&collection.elements(), |a, b| {
+            (self.compare)(a, b) != std::cmp::Ordering::Greater
+        })
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("be sorted by the given comparison"),
+            object: None,
+        }
+    }
+}
+
+/// Matches if the elements of `actual` are sorted in ascending order of the keys extracted by
+/// `key`, as they would be by [`slice::sort_by_key`].
+///
+/// Supports the same collections as [`contain_in_order`].
+///
+/// [`slice::sort_by_key`]: https://doc.rust-lang.org/std/primitive.slice.html#method.sort_by_key
+/// [`contain_in_order`]: fn.contain_in_order.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::be_sorted_by_key};
+/// expect(&vec!["c", "bb", "aaa"]).to(be_sorted_by_key(|s: &&str| s.len()));
+/// expect(&vec!["bb", "c"]).not_to(be_sorted_by_key(|s: &&str| s.len()));
+/// ```
+pub fn be_sorted_by_key<F>(key: F) -> BeSortedByKeyMatcher<F> {
+    BeSortedByKeyMatcher { key }
+}
+
+pub struct BeSortedByKeyMatcher<F> {
+    key: F,
+}
+
+impl<
+        T: std::fmt::Debug,
+        V: Sequence<Item = T>,
+        K: PartialOrd + std::fmt::Debug,
+        F: Fn(&T) -> K,
+    > Matcher<V> for BeSortedByKeyMatcher<F>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        self.match_result(collection).is_match()
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let elements = collection.elements();
+        match first_out_of_order(&elements, |a, b| (self.key)(a) <= (self.key)(b)) {
+            None => MatchResult::Matched,
+            Some(i) => MatchResult::mismatched(format!(
+                "elements at indexes {} and {} were out of order: {:?} (key {:?}) came before {:?} (key {:?})",
+                i,
+                i + 1,
+                elements[i],
+                (self.key)(elements[i]),
+                elements[i + 1],
+                (self.key)(elements[i + 1])
+            )),
+        }
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("be sorted by the given key"),
+            object: None,
+        }
+    }
+}
+
+/// Matches if every element of `actual` is greater than the one before it.
+///
+/// Supports the same collections as [`contain_in_order`].
+///
+/// [`contain_in_order`]: fn.contain_in_order.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::be_strictly_increasing};
+/// expect(&vec![1, 2, 3]).to(be_strictly_increasing());
+/// expect(&vec![1, 2, 2]).not_to(be_strictly_increasing());
+/// ```
+pub fn be_strictly_increasing() -> BeStrictlyIncreasingMatcher {
+    BeStrictlyIncreasingMatcher {}
+}
+
+pub struct BeStrictlyIncreasingMatcher {}
+
+impl<T: PartialOrd + std::fmt::Debug, V: Sequence<Item = T>> Matcher<V>
+    for BeStrictlyIncreasingMatcher
+{
+    fn match_value(&self, collection: &V) -> bool {
+        self.match_result(collection).is_match()
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        explain_order(&collection.elements(), |a, b| a < b)
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("be strictly increasing"),
+            object: None,
+        }
+    }
+}
+
+/// Returns the index of the first element that is not `in_order` with the one after it.
+fn first_out_of_order<T>(elements: &[&T], in_order: impl Fn(&T, &T) -> bool) -> Option<usize> {
+    elements
+        .windows(2)
+        .position(|pair| !in_order(pair[0], pair[1]))
+}
+
+fn explain_order<T: std::fmt::Debug>(
+    elements: &[&T],
+    in_order: impl Fn(&T, &T) -> bool,
+) -> MatchResult {
+    match first_out_of_order(elements, in_order) {
+        None => MatchResult::Matched,
+        Some(i) => MatchResult::mismatched(format!(
+            "elements at indexes {} and {} were out of order: {:?} came before {:?}",
+            i,
+            i + 1,
+            elements[i],
+            elements[i + 1]
+        )),
+    }
+}
+
+/// Matches if no two elements of `actual` are equal.
+///
+/// Supports the same collections as [`contain_exactly`], as long as their elements implement
+/// [`Hash`] and [`Eq`]: use [`have_unique_elements_by`] otherwise. On failure, lists every
+/// duplicated element along with the indexes where it appears.
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+/// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
+/// [`Eq`]: https://doc.rust-lang.org/std/cmp/trait.Eq.html
+/// [`have_unique_elements_by`]: fn.have_unique_elements_by.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::have_unique_elements};
+/// expect(&vec![1, 2, 3]).to(have_unique_elements());
+/// expect(&vec![1, 2, 1]).not_to(have_unique_elements());
+/// ```
+pub fn have_unique_elements() -> HaveUniqueElementsMatcher {
+    HaveUniqueElementsMatcher {}
+}
+
+pub struct HaveUniqueElementsMatcher {}
+
+impl<T: Hash + Eq + std::fmt::Debug, V: Collection<Item = T>> Matcher<V>
+    for HaveUniqueElementsMatcher
+{
+    fn match_value(&self, collection: &V) -> bool {
+        let mut seen = HashSet::new();
+        collection
+            .elements()
+            .into_iter()
+            .all(|element| seen.insert(element))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let elements = collection.elements();
+        explain_duplicates(&elements, duplicates(&elements))
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("have unique elements"),
+            object: None,
+        }
+    }
+}
+
+/// Matches if no two elements of `actual` are equal according to `eq`.
+///
+/// Supports the same collections as [`contain_exactly`]. Unlike [`have_unique_elements`], it
+/// doesn't need elements to implement [`Hash`] and [`Eq`], at the cost of comparing every pair of
+/// them. On failure, lists every duplicated element along with the indexes where it appears.
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+/// [`have_unique_elements`]: fn.have_unique_elements.html
+/// [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
+/// [`Eq`]: https://doc.rust-lang.org/std/cmp/trait.Eq.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::have_unique_elements_by};
+/// let eq = |a: &f64, b: &f64| a == b;
+/// expect(&vec![0.1, 0.2, 0.3]).to(have_unique_elements_by(eq));
+/// expect(&vec![0.1, 0.2, 0.1]).not_to(have_unique_elements_by(eq));
+/// ```
+pub fn have_unique_elements_by<F>(eq: F) -> HaveUniqueElementsByMatcher<F> {
+    HaveUniqueElementsByMatcher { eq }
+}
+
+pub struct HaveUniqueElementsByMatcher<F> {
+    eq: F,
+}
+
+impl<T: std::fmt::Debug, V: Collection<Item = T>, F: Fn(&T, &T) -> bool> Matcher<V>
+    for HaveUniqueElementsByMatcher<F>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        let elements = collection.elements();
+        !(0..elements.len())
+            .any(|i| (i + 1..elements.len()).any(|j| (self.eq)(elements[i], elements[j])))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let elements = collection.elements();
+        explain_duplicates(&elements, duplicates_by(&elements, &self.eq))
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("have unique elements by the given equality"),
+            object: None,
+        }
+    }
+}
+
+/// Matches if no two elements of `actual` have equal keys, as extracted by `key`.
+///
+/// Supports the same collections as [`contain_exactly`]. On failure, lists every duplicated key
+/// along with the indexes and values of the elements that share it.
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::have_unique_elements_by_key};
+/// let id = |pair: &(u32, &str)| pair.0;
+/// expect(&vec![(1, "a"), (2, "a")]).to(have_unique_elements_by_key(id));
+/// expect(&vec![(1, "a"), (1, "b")]).not_to(have_unique_elements_by_key(id));
+/// ```
+pub fn have_unique_elements_by_key<F>(key: F) -> HaveUniqueElementsByKeyMatcher<F> {
+    HaveUniqueElementsByKeyMatcher { key }
+}
+
+pub struct HaveUniqueElementsByKeyMatcher<F> {
+    key: F,
+}
+
+impl<
+        T: std::fmt::Debug,
+        V: Collection<Item = T>,
+        K: Hash + Eq + std::fmt::Debug,
+        F: Fn(&T) -> K,
+    > Matcher<V> for HaveUniqueElementsByKeyMatcher<F>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        let mut seen = HashSet::new();
+        collection
+            .elements()
+            .into_iter()
+            .all(|element| seen.insert((self.key)(element)))
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let elements = collection.elements();
+        let keys: Vec<K> = elements.iter().map(|element| (self.key)(element)).collect();
+        let duplicates: Vec<String> = duplicates(&keys.iter().collect::<Vec<_>>())
+            .into_iter()
+            .map(|indexes| {
+                let values: Vec<String> = indexes
+                    .iter()
+                    .map(|&i| format!("{:?}", elements[i]))
+                    .collect();
+                format!(
+                    "{:?} at indexes {}: {}",
+                    keys[indexes[0]],
+                    join(&indexes),
+                    values.join(", ")
+                )
+            })
+            .collect();
+        if duplicates.is_empty() {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(format!("duplicate keys:\n{}", duplicates.join("\n")))
+        }
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("have unique elements by the given key"),
+            object: None,
+        }
+    }
+}
+
+/// Matches if `element` appears exactly `n` times in `actual`.
+///
+/// Supports the same collections as [`contain_exactly`].
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+///
+/// Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::contain_n_times};
+/// expect(&vec![1, 2, 1]).to(contain_n_times(1, 2));
+/// expect(&vec![1, 2, 1]).not_to(contain_n_times(2, 2));
+/// ```
+pub fn contain_n_times<T>(element: T, n: usize) -> ContainNTimesMatcher<T> {
+    ContainNTimesMatcher { element, n }
+}
+
+pub struct ContainNTimesMatcher<T> {
+    element: T,
+    n: usize,
+}
+
+impl<T: PartialEq + std::fmt::Debug, V: Collection<Item = T>> Matcher<V>
+    for ContainNTimesMatcher<T>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        self.match_result(collection).is_match()
+    }
+
+    fn match_result(&self, collection: &V) -> MatchResult {
+        let indexes: Vec<usize> = collection
+            .elements()
+            .iter()
+            .enumerate()
+            .filter(|(_, element)| **element == &self.element)
+            .map(|(i, _)| i)
+            .collect();
+        if indexes.len() == self.n {
+            MatchResult::Matched
+        } else if indexes.is_empty() {
+            MatchResult::mismatched(format!("element {:?} was missing", self.element))
+        } else {
+            MatchResult::mismatched(format!(
+                "element {:?} appeared {}, at indexes {}",
+                self.element,
+                times(indexes.len()),
+                join(&indexes)
+            ))
+        }
+    }
+
+    fn description(&self, _: &V) -> Description {
+        Description {
+            verb: String::from("contain"),
+            object: Some(format!("{:?} exactly {}", self.element, times(self.n))),
+        }
+    }
+}
+
+/// Groups the indexes of equal elements, in order of first appearance, keeping only the groups
+/// with more than one index.
+fn duplicates<T: Hash + Eq>(elements: &[&T]) -> Vec<Vec<usize>> {
+    let mut group_of: HashMap<&T, usize> = HashMap::new();
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for (i, &element) in elements.iter().enumerate() {
+        let group = *group_of.entry(element).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(i);
+    }
+    groups.retain(|group| group.len() > 1);
+    groups
+}
+
+/// Like [`duplicates`], for elements that can only be compared with `eq`, pair by pair.
+fn duplicates_by<T, F: Fn(&T, &T) -> bool>(elements: &[&T], eq: F) -> Vec<Vec<usize>> {
+    let mut seen = vec![false; elements.len()];
+    let mut groups = Vec::new();
+    for i in 0..elements.len() {
+        if seen[i] {
+            continue;
+        }
+        let group: Vec<usize> = (i..elements.len())
+            .filter(|&j| eq(elements[j], elements[i]))
+            .collect();
+        for &j in &group {
+            seen[j] = true;
+        }
+        if group.len() > 1 {
+            groups.push(group);
+        }
+    }
+    groups
+}
+
+fn explain_duplicates<T: std::fmt::Debug>(elements: &[&T], groups: Vec<Vec<usize>>) -> MatchResult {
+    if groups.is_empty() {
+        return MatchResult::Matched;
+    }
+    let duplicates: Vec<String> = groups
+        .into_iter()
+        .map(|indexes| format!("{:?} at indexes {}", elements[indexes[0]], join(&indexes)))
+        .collect();
+    MatchResult::mismatched(format!("duplicate elements:\n{}", duplicates.join("\n")))
+}
+
+fn join(indexes: &[usize]) -> String {
+    indexes
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn times(n: usize) -> String {
+    if n == 1 {
+        String::from("once")
+    } else {
+        format!("{} times", n)
+    }
+}
+
+/// Pairs up `expected` with `actual` elements, so that as many pairs as possible `fit`, and
+/// returns the indexes of the expected and actual elements that were left alone.
+///
+/// Elements are paired up through augmenting paths, so that an early greedy choice never prevents
+/// a later element from finding its pair.
+fn pair_up<E, A>(
+    expected: &[E],
+    actual: &[A],
+    fits: impl Fn(&E, &A) -> bool,
+) -> (Vec<usize>, Vec<usize>) {
+    let candidates: Vec<Vec<usize>> = expected
+        .iter()
+        .map(|e| (0..actual.len()).filter(|&a| fits(e, &actual[a])).collect())
+        .collect();
+    let mut paired_with: Vec<Option<usize>> = vec![None; actual.len()];
+    let mut missing = Vec::new();
+    for e in 0..expected.len() {
+        let mut visited = vec![false; actual.len()];
+        if !augment(e, &candidates, &mut visited, &mut paired_with) {
+            missing.push(e);
+        }
+    }
+    let unexpected = (0..actual.len())
+        .filter(|&a| paired_with[a].is_none())
+        .collect();
+    (missing, unexpected)
+}
+
+fn augment(
+    e: usize,
+    candidates: &[Vec<usize>],
+    visited: &mut [bool],
+    paired_with: &mut [Option<usize>],
+) -> bool {
+    for &a in &candidates[e] {
+        if visited[a] {
+            continue;
+        }
+        visited[a] = true;
+        let free = match paired_with[a] {
+            None => true,
+            Some(other) => augment(other, candidates, visited, paired_with),
+        };
+        if free {
+            paired_with[a] = Some(e);
+            return true;
+        }
+    }
+    false
+}
+
+fn explain_pairing<T: std::fmt::Debug>(
+    missing: Vec<String>,
+    missing_label: &str,
+    unexpected: &[&T],
+) -> MatchResult {
+    let mut reasons = Vec::new();
+    if !missing.is_empty() {
+        reasons.push(format!("{}: {}", missing_label, missing.join(", ")));
+    }
+    if !unexpected.is_empty() {
+        reasons.push(format!("unexpected elements: {:?}", unexpected));
+    }
+    if reasons.is_empty() {
+        MatchResult::Matched
+    } else {
+        MatchResult::mismatched(reasons.join("\n"))
+    }
+}
+
+/// A collection whose elements can be visited one by one, as needed by [`contain_exactly`] and
+/// [`consist_of`].
+///
+/// [`contain_exactly`]: fn.contain_exactly.html
+/// [`consist_of`]: fn.consist_of.html
+pub trait Collection {
+    type Item;
+
+    fn elements(&self) -> Vec<&Self::Item>;
+}
+
+impl<T, const N: usize> Collection for [T; N] {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+impl<T> Collection for std::vec::Vec<T> {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+impl<T> Collection for std::collections::VecDeque<T> {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+impl<T> Collection for std::collections::LinkedList<T> {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+impl<T> Collection for std::collections::HashSet<T> {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+impl<T> Collection for std::collections::BTreeSet<T> {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+impl<T> Collection for &[T] {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+/// A [`Collection`] whose elements have a meaningful order, as needed by [`contain_in_order`] and
+/// [`contain_slice`].
+///
+/// [`Collection`]: trait.Collection.html
+/// [`contain_in_order`]: fn.contain_in_order.html
+/// [`contain_slice`]: fn.contain_slice.html
+pub trait Sequence: Collection {}
+
+impl<T, const N: usize> Sequence for [T; N] {}
+
+impl<T> Sequence for std::vec::Vec<T> {}
+
+impl<T> Sequence for std::collections::VecDeque<T> {}
+
+impl<T> Sequence for std::collections::LinkedList<T> {}
+
+impl<T> Sequence for &[T] {}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        all_elements, any_element, be_disjoint_from, be_empty, be_sorted, be_sorted_by,
+        be_sorted_by_key, be_strictly_increasing, be_subset_of, be_superset_of, consist_of,
+        contain, contain_all, contain_any, contain_exactly, contain_in_order, contain_n_times,
+        contain_slice, equal_set, exactly_n_elements, have_len, have_unique_elements,
+        have_unique_elements_by, have_unique_elements_by_key, intersect, no_element, Collection,
+        Container, Emptiable, HaveLen,
+    };
+    use crate::{
+        matchers::{
+            equal,
+            numeric::be_greater_than,
+            option::{be_none, be_some},
+        },
+        Description, MatchResult, Matcher,
+    };
+
+    #[test]
+    fn contain_matcher_should_match_if_collection_contains_element() {
+        assert!(contain("foo").match_value(&vec!["foo"]))
+    }
+
+    #[test]
+    fn contain_matcher_should_not_match_if_collection_does_not_contain_element() {
+        assert!(!contain("foo").match_value(&vec!["bar"]))
+    }
+
+    #[test]
+    fn contain_matcher_should_explain_a_mismatch() {
+        assert_eq!(
+            contain(3).match_result(&vec![1, 2]),
+            MatchResult::mismatched("element 3 was missing")
+        )
+    }
+
+    #[test]
+    fn contain_matcher_should_describe_itself() {
+        let description = contain("foo").description(&vec!["bar"]);
+        assert_eq!(description.verb, String::from("contain"));
+        assert_eq!(description.object, Some(String::from("\"foo\"")));
+    }
+
+    #[test]
+    fn be_empty_matcher_should_match_if_collection_is_empty() {
+        assert!(be_empty().match_value(&std::vec::Vec::<i32>::new()))
+    }
+
+    #[test]
+    fn be_empty_matcher_should_not_match_if_collection_is_not_empty() {
+        assert!(!be_empty().match_value(&vec![42]))
+    }
+
+    #[test]
+    fn be_empty_matcher_should_describe_itself() {
+        let description = be_empty().description(&vec!["foo"]);
+        assert_eq!(description.verb, String::from("be empty"));
+        assert_eq!(description.object, None);
+    }
+
+    #[test]
+    fn have_len_matcher_should_match_lengths() {
+        assert!(have_len(3).match_value(&vec![1, 2, 3]));
+        assert!(!have_len(2).match_value(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn have_len_matcher_should_accept_matchers() {
+        assert!(have_len(be_greater_than(2)).match_value(&"foo"));
+        assert!(!have_len(be_greater_than(3)).match_value(&String::from("foo")));
+    }
+
+    #[test]
+    fn have_len_matcher_should_explain_a_mismatch_with_the_actual_length() {
+        assert_eq!(
+            have_len(2).match_result(&(0..1000).collect::<Vec<_>>()),
+            MatchResult::mismatched("length was 1000")
+        )
+    }
+
+    #[test]
+    fn have_len_matcher_should_describe_itself() {
+        let description = have_len(be_greater_than(3)).description(&vec![1]);
+        assert_eq!(
+            description.verb,
+            String::from("have a length to be greater than")
+        );
+        assert_eq!(description.object, Some(String::from("3")));
+    }
+
+    #[test]
+    fn contain_exactly_matcher_should_match_in_any_order() {
+        assert!(contain_exactly([1, 2, 3]).match_value(&vec![3, 1, 2]));
+        assert!(contain_exactly(vec!["a"]).match_value(&["a"]));
+    }
+
+    #[test]
+    fn contain_exactly_matcher_should_respect_multiplicity() {
+        assert!(contain_exactly([1, 1, 2]).match_value(&vec![1, 2, 1]));
+        assert!(!contain_exactly([1, 2]).match_value(&vec![1, 2, 2]));
+    }
+
+    #[test]
+    fn contain_exactly_matcher_should_explain_missing_and_unexpected_elements() {
+        assert_eq!(
+            contain_exactly([1, 2, 2, 3]).match_result(&vec![4, 2, 1, 5]),
+            MatchResult::mismatched("missing elements: 2, 3\nunexpected elements: [4, 5]")
+        )
+    }
+
+    #[test]
+    fn contain_exactly_matcher_should_describe_itself() {
+        let description = contain_exactly([1, 2]).description(&vec![1]);
+        assert_eq!(description.verb, String::from("contain exactly"));
+        assert_eq!(description.object, Some(String::from("[1, 2]")));
+    }
+
+    #[test]
+    fn consist_of_matcher_should_pair_up_elements_and_matchers() {
+        // A greedy pairing would give 5 to the first matcher and leave 2 alone.
+        let matcher = consist_of((be_greater_than(1), be_greater_than(4)));
+        assert!(matcher.match_value(&vec![5, 2]));
+        assert!(!matcher.match_value(&vec![5, 0]));
+    }
+
+    #[test]
+    fn consist_of_matcher_should_explain_missing_and_unexpected_elements() {
+        assert_eq!(
+            consist_of((equal(1), be_greater_than(4))).match_result(&vec![1, 3]),
+            MatchResult::mismatched(
+                "no element matched: be greater than 4\nunexpected elements: [3]"
+            )
+        );
+        assert_eq!(
+            consist_of([equal(1)]).match_result(&Vec::<u32>::new()),
+            MatchResult::mismatched("was empty")
+        );
+    }
+
+    #[test]
+    fn consist_of_matcher_should_describe_itself() {
+        let description = consist_of((equal(1), be_greater_than(4))).description(&vec![1]);
+        assert_eq!(description.verb, String::from("consist of elements to"));
+        assert_eq!(
+            description.object,
+            Some(String::from("equal 1, be greater than 4"))
+        );
+    }
+
+    #[test]
+    fn contain_all_matcher_should_match_if_all_elements_are_contained() {
+        assert!(contain_all([3, 1]).match_value(&vec![1, 2, 3]));
+        assert!(!contain_all([3, 4]).match_value(&std::collections::HashSet::from([1, 2, 3])));
+    }
+
+    #[test]
+    fn contain_all_matcher_should_explain_missing_elements() {
+        assert_eq!(
+            contain_all([1, 4, 5]).match_result(&vec![1, 2, 3]),
+            MatchResult::mismatched("missing elements: 4, 5")
+        )
+    }
+
+    #[test]
+    fn contain_all_matcher_should_describe_itself() {
+        let description = contain_all([1, 2]).description(&vec![1]);
+        assert_eq!(description.verb, String::from("contain all of"));
+        assert_eq!(description.object, Some(String::from("[1, 2]")));
+    }
+
+    #[test]
+    fn contain_any_matcher_should_match_if_any_element_is_contained() {
+        assert!(contain_any([4, 3]).match_value(&vec![1, 2, 3]));
+        assert!(!contain_any([4, 5]).match_value(&vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn contain_any_matcher_should_describe_itself() {
+        let description = contain_any([1, 2]).description(&vec![1]);
+        assert_eq!(description.verb, String::from("contain any of"));
+        assert_eq!(description.object, Some(String::from("[1, 2]")));
+    }
+
+    #[test]
+    fn contain_in_order_matcher_should_match_subsequences() {
+        assert!(contain_in_order([1, 3, 4]).match_value(&vec![1, 2, 3, 4]));
+        assert!(contain_in_order([2, 2]).match_value(&[2, 1, 2].as_slice()));
+        assert!(!contain_in_order([2, 2]).match_value(&vec![1, 2]));
+    }
+
+    #[test]
+    fn contain_in_order_matcher_should_explain_which_element_broke_the_order() {
+        assert_eq!(
+            contain_in_order([1, 3, 2]).match_result(&vec![1, 2, 3]),
+            MatchResult::mismatched("element 2 was not found after element 3 at index 2")
+        );
+        assert_eq!(
+            contain_in_order([1, 4]).match_result(&vec![1, 2, 3]),
+            MatchResult::mismatched("element 4 was missing")
+        );
+    }
+
+    #[test]
+    fn contain_in_order_matcher_should_describe_itself() {
+        let description = contain_in_order([1, 2]).description(&vec![1]);
+        assert_eq!(description.verb, String::from("contain in order"));
+        assert_eq!(description.object, Some(String::from("[1, 2]")));
+    }
+
+    #[test]
+    fn contain_slice_matcher_should_match_contiguous_runs() {
+        assert!(contain_slice([2, 3]).match_value(&vec![1, 2, 3, 4]));
+        assert!(contain_slice(Vec::<u32>::new()).match_value(&vec![1]));
+        assert!(!contain_slice([2, 4]).match_value(&vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn contain_slice_matcher_should_explain_where_the_run_broke() {
+        assert_eq!(
+            contain_slice([2, 3, 5]).match_result(&vec![2, 1, 2, 3, 4]),
+            MatchResult::mismatched("[2, 3] was found at index 2, but followed by 4 instead of 5")
+        );
+        assert_eq!(
+            contain_slice([3, 4]).match_result(&vec![1, 2, 3]),
+            MatchResult::mismatched("[3] was found at index 2, but the collection ended before 4")
+        );
+        assert_eq!(
+            contain_slice([5]).match_result(&vec![1, 2, 3]),
+            MatchResult::mismatched("element 5 was missing")
+        );
+    }
+
+    #[test]
+    fn contain_slice_matcher_should_describe_itself() {
+        let description = contain_slice([1, 2]).description(&vec![1]);
+        assert_eq!(description.verb, String::from("contain slice"));
+        assert_eq!(description.object, Some(String::from("[1, 2]")));
+    }
+
+    #[test]
+    fn be_subset_of_matcher_should_match_subsets() {
+        let set = std::collections::HashSet::from([1, 2, 3]);
+        assert!(be_subset_of(set.clone()).match_value(&vec![3, 1, 1]));
+        assert!(be_subset_of(set).match_value(&Vec::<u32>::new()));
+        assert!(!be_subset_of([1, 2]).match_value(&std::collections::BTreeSet::from([1, 4])));
+    }
+
+    #[test]
+    fn be_subset_of_matcher_should_explain_extra_elements() {
+        assert_eq!(
+            be_subset_of([1, 2]).match_result(&vec![1, 4, 5]),
+            MatchResult::mismatched("elements not in the set: [4, 5]")
+        )
+    }
+
+    #[test]
+    fn be_subset_of_matcher_should_describe_itself() {
+        let description = be_subset_of([1, 2]).description(&vec![1]);
+        assert_eq!(description.verb, String::from("be a subset of"));
+        assert_eq!(description.object, Some(String::from("[1, 2]")));
+    }
+
+    #[test]
+    fn be_superset_of_matcher_should_match_supersets() {
+        assert!(be_superset_of([1, 3]).match_value(&std::collections::HashSet::from([1, 2, 3])));
+        assert!(!be_superset_of(vec![1, 4]).match_value(&vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn be_superset_of_matcher_should_explain_missing_elements() {
+        assert_eq!(
+            be_superset_of([1, 4, 5]).match_result(&vec![1, 2]),
+            MatchResult::mismatched("missing elements: [4, 5]")
+        )
+    }
+
+    #[test]
+    fn be_superset_of_matcher_should_describe_itself() {
+        let description = be_superset_of([1, 2]).description(&vec![1]);
+        assert_eq!(description.verb, String::from("be a superset of"));
+        assert_eq!(description.object, Some(String::from("[1, 2]")));
+    }
+
+    #[test]
+    fn be_disjoint_from_matcher_should_match_if_no_element_is_in_common() {
+        assert!(be_disjoint_from([4, 5]).match_value(&vec![1, 2, 3]));
+        assert_eq!(
+            be_disjoint_from([2, 3, 4]).match_result(&std::collections::BTreeSet::from([1, 2, 3])),
+            MatchResult::mismatched("elements in common: [2, 3]")
+        );
+    }
+
+    #[test]
+    fn be_disjoint_from_matcher_should_describe_itself() {
+        let description = be_disjoint_from([1, 2]).description(&vec![1]);
+        assert_eq!(description.verb, String::from("be disjoint from"));
+        assert_eq!(description.object, Some(String::from("[1, 2]")));
+    }
+
+    #[test]
+    fn intersect_matcher_should_match_if_some_element_is_in_common() {
+        assert!(intersect([3, 4]).match_value(&vec![1, 2, 3]));
+        assert_eq!(
+            intersect([4, 5]).match_result(&vec![1, 2, 3]),
+            MatchResult::mismatched("no elements in common")
+        );
+    }
+
+    #[test]
+    fn intersect_matcher_should_describe_itself() {
+        let description = intersect([1, 2]).description(&vec![1]);
+        assert_eq!(description.verb, String::from("intersect"));
+        assert_eq!(description.object, Some(String::from("[1, 2]")));
+    }
+
+    #[test]
+    fn equal_set_matcher_should_match_sets_with_the_same_elements() {
+        let set = std::collections::HashSet::from([1, 2, 3]);
+        assert!(equal_set(set.clone()).match_value(&std::collections::BTreeSet::from([3, 2, 1])));
+        assert!(equal_set([1, 2]).match_value(&vec![2, 1, 1]));
+        assert!(!equal_set(set).match_value(&std::collections::HashSet::from([1, 2])));
+    }
+
+    #[test]
+    fn equal_set_matcher_should_report_the_differing_elements() {
+        assert_eq!(
+            equal_set(std::collections::HashSet::from(["b", "a"]))
+                .match_result(&std::collections::HashSet::from(["c", "b"])),
+            MatchResult::mismatched(
+                "set diff (- expected, + actual):\n\
+                 elements only in expected:\n  \"a\"\n\
+                 elements only in actual:\n  \"c\"\n"
+            )
+        )
+    }
+
+    #[test]
+    fn all_elements_matcher_should_match_if_every_element_matches() {
+        assert!(all_elements(be_some()).match_value(&vec![Some(1), Some(2)]));
+        assert!(all_elements(be_some()).match_value(&Vec::<Option<u32>>::new()));
+        assert!(!all_elements(be_some()).match_value(&vec![Some(1), None]));
+    }
+
+    #[test]
+    fn all_elements_matcher_should_list_mismatching_elements() {
+        assert_eq!(
+            all_elements(be_greater_than(2)).match_result(&vec![1, 3, 2]),
+            MatchResult::mismatched("mismatching elements:\n[0] 1\n[2] 2")
+        );
+        assert_eq!(
+            all_elements(be_some()).match_result(&vec![Some(1), None]),
+            MatchResult::mismatched("mismatching elements:\n[1] None")
+        );
+    }
+
+    #[test]
+    fn all_elements_matcher_should_only_list_the_first_mismatching_elements() {
+        let elements: Vec<u32> = (0..15).collect();
+        let listed: Vec<String> = (0..10).map(|i| format!("[{}] {}", i, i)).collect();
+        assert_eq!(
+            all_elements(be_greater_than(20)).match_result(&elements),
+            MatchResult::mismatched(format!(
+                "mismatching elements:\n{}\n... and 5 more",
+                listed.join("\n")
+            ))
+        );
+    }
+
+    #[test]
+    fn all_elements_matcher_should_run_inner_once_per_element() {
+        struct CountingMatcher(std::cell::Cell<usize>);
+
+        impl Matcher<u32> for CountingMatcher {
+            fn match_value(&self, actual: &u32) -> bool {
+                self.0.set(self.0.get() + 1);
+                *actual > 1
+            }
+
+            fn description(&self, _: &u32) -> Description {
+                Description {
+                    verb: String::from("count"),
+                    object: None,
+                }
+            }
+        }
+
+        let matcher = all_elements(CountingMatcher(std::cell::Cell::new(0)));
+        assert!(!matcher.match_result(&vec![1, 2, 0]).is_match());
+        assert_eq!(matcher.inner.0.get(), 3);
+    }
+
+    #[test]
+    fn all_elements_matcher_should_describe_itself() {
+        let description = all_elements(be_greater_than(2)).description(&vec![1]);
+        assert_eq!(
+            description.verb,
+            String::from("have every element be greater than")
+        );
+        assert_eq!(description.object, Some(String::from("2")));
+    }
+
+    #[test]
+    fn any_element_matcher_should_match_if_some_element_matches() {
+        assert!(any_element(be_greater_than(3)).match_value(&vec![1, 5]));
+        assert!(!any_element(be_greater_than(3)).match_value(&vec![1, 2]));
+    }
+
+    #[test]
+    fn any_element_matcher_should_list_mismatching_elements() {
+        assert_eq!(
+            any_element(be_none()).match_result(&vec![Some(1)]),
+            MatchResult::mismatched("mismatching elements:\n[0] Some(1): was a Some")
+        );
+        assert_eq!(
+            any_element(be_none()).match_result(&Vec::<Option<u32>>::new()),
+            MatchResult::mismatched("was empty")
+        );
+    }
+
+    #[test]
+    fn any_element_matcher_should_describe_itself() {
+        let description = any_element(equal(2).or(equal(3))).description(&vec![1]);
+        assert_eq!(
+            description.verb,
+            String::from("have some element (equal 2 or equal 3)")
+        );
+        assert_eq!(description.object, None);
+    }
+
+    #[test]
+    fn no_element_matcher_should_match_if_no_element_matches() {
+        assert!(no_element(be_none()).match_value(&vec![Some(1)]));
+        assert!(!no_element(be_none()).match_value(&vec![Some(1), None]));
+    }
+
+    #[test]
+    fn no_element_matcher_should_list_matching_elements() {
+        assert_eq!(
+            no_element(be_greater_than(2)).match_result(&vec![3, 1, 4]),
+            MatchResult::mismatched("matching elements:\n[0] 3\n[2] 4")
+        )
+    }
+
+    #[test]
+    fn no_element_matcher_should_describe_itself() {
+        let description = no_element(equal(2)).description(&Vec::<u32>::new());
+        assert_eq!(
+            description.verb,
+            String::from("have no element match the inner matcher")
+        );
+        assert_eq!(description.object, None);
+    }
+
+    #[test]
+    fn exactly_n_elements_matcher_should_count_matching_elements() {
+        assert!(exactly_n_elements(2, be_greater_than(3)).match_value(&vec![1, 5, 7]));
+        assert!(!exactly_n_elements(1, be_greater_than(3)).match_value(&vec![1, 5, 7]));
+    }
+
+    #[test]
+    fn exactly_n_elements_matcher_should_list_matching_elements() {
+        assert_eq!(
+            exactly_n_elements(1, be_greater_than(3)).match_result(&vec![1, 5, 7]),
+            MatchResult::mismatched("2 matching elements:\n[1] 5\n[2] 7")
+        );
+        assert_eq!(
+            exactly_n_elements(1, be_greater_than(3)).match_result(&vec![1]),
+            MatchResult::mismatched("no elements matched")
+        );
+    }
+
+    #[test]
+    fn exactly_n_elements_matcher_should_describe_itself() {
+        let description = exactly_n_elements(2, equal(3)).description(&vec![1]);
+        assert_eq!(
+            description.verb,
+            String::from("have exactly 2 elements equal")
+        );
+        assert_eq!(description.object, Some(String::from("3")));
+    }
+
+    #[test]
+    fn be_sorted_matcher_should_match_sorted_sequences() {
+        assert!(be_sorted().match_value(&vec![1, 2, 2, 3]));
+        assert!(be_sorted().match_value(&Vec::<u32>::new()));
+        assert!(!be_sorted().match_value(&[1.0, f64::NAN]));
+        assert!(be_sorted()
+            .descending()
+            .match_value(&std::collections::VecDeque::from([3, 2, 2])));
+        assert!(!be_sorted().descending().match_value(&[1, 2].as_slice()));
+    }
+
+    #[test]
+    fn be_sorted_matcher_should_explain_the_first_pair_out_of_order() {
+        assert_eq!(
+            be_sorted().match_result(&vec![1, 3, 2, 0]),
+            MatchResult::mismatched(
+                "elements at indexes 1 and 2 were out of order: 3 came before 2"
+            )
+        )
+    }
+
+    #[test]
+    fn be_sorted_matcher_should_describe_itself() {
+        let description = be_sorted().description(&vec![1]);
+        assert_eq!(description.verb, String::from("be sorted"));
+        assert_eq!(description.object, None);
+
+        let description = be_sorted().descending().description(&vec![1]);
+        assert_eq!(
+            description.verb,
+            String::from("be sorted in descending order")
+        );
+    }
+
+    #[test]
+    fn be_sorted_by_matcher_should_use_the_comparison() {
+        let by_length = |a: &&str, b: &&str| a.len().cmp(&b.len());
+        assert!(be_sorted_by(by_length).match_value(&vec!["a", "bb", "ccc"]));
+        assert_eq!(
+            be_sorted_by(by_length).match_result(&vec!["bb", "a"]),
+            MatchResult::mismatched(
+                "elements at indexes 0 and 1 were out of order: \"bb\" came before \"a\""
+            )
+        );
+    }
+
+    #[test]
+    fn be_sorted_by_key_matcher_should_compare_keys() {
+        let second = |pair: &(u32, u32)| pair.1;
+        assert!(be_sorted_by_key(second).match_value(&vec![(3, 1), (1, 2)]));
+        assert_eq!(
+            be_sorted_by_key(second).match_result(&vec![(3, 2), (1, 1)]),
+            MatchResult::mismatched(
+                "elements at indexes 0 and 1 were out of order: (3, 2) (key 2) came before (1, 1) (key 1)"
+            )
+        );
+    }
+
+    #[test]
+    fn be_strictly_increasing_matcher_should_reject_repeated_elements() {
+        assert!(be_strictly_increasing().match_value(&vec![1, 2, 3]));
+        assert_eq!(
+            be_strictly_increasing().match_result(&vec![1, 2, 2]),
+            MatchResult::mismatched(
+                "elements at indexes 1 and 2 were out of order: 2 came before 2"
+            )
+        );
+    }
+
+    #[test]
+    fn be_strictly_increasing_matcher_should_describe_itself() {
+        let description = be_strictly_increasing().description(&vec![1]);
+        assert_eq!(description.verb, String::from("be strictly increasing"));
+        assert_eq!(description.object, None);
+    }
+
+    #[test]
+    fn have_unique_elements_matcher_should_match_if_no_element_is_repeated() {
+        assert!(have_unique_elements().match_value(&vec![1, 2, 3]));
+        assert!(!have_unique_elements().match_value(&std::collections::LinkedList::from([1, 1])));
+    }
+
+    #[test]
+    fn have_unique_elements_matcher_should_list_duplicates_and_their_indexes() {
+        assert_eq!(
+            have_unique_elements().match_result(&vec!["a", "b", "a", "c", "b", "a"]),
+            MatchResult::mismatched(
+                "duplicate elements:\n\"a\" at indexes 0, 2, 5\n\"b\" at indexes 1, 4"
+            )
+        )
+    }
+
+    #[test]
+    fn have_unique_elements_matcher_should_describe_itself() {
+        let description = have_unique_elements().description(&vec![1]);
+        assert_eq!(description.verb, String::from("have unique elements"));
+        assert_eq!(description.object, None);
+    }
+
+    #[test]
+    fn have_unique_elements_by_key_matcher_should_compare_keys() {
+        let id = |pair: &(u32, char)| pair.0;
+        assert!(have_unique_elements_by_key(id).match_value(&vec![(1, 'a'), (2, 'a')]));
+        assert_eq!(
+            have_unique_elements_by_key(id).match_result(&vec![(1, 'a'), (2, 'b'), (1, 'c')]),
+            MatchResult::mismatched("duplicate keys:\n1 at indexes 0, 2: (1, 'a'), (1, 'c')")
+        );
+    }
+
+    #[test]
+    fn have_unique_elements_by_matcher_should_compare_every_pair() {
+        let eq = |a: &f64, b: &f64| a == b;
+        assert!(have_unique_elements_by(eq).match_value(&vec![0.1, 0.2, 0.3]));
+        assert_eq!(
+            have_unique_elements_by(eq).match_result(&vec![0.1, 0.2, 0.1, 0.2]),
+            MatchResult::mismatched(
+                "duplicate elements:\n0.1 at indexes 0, 2\n0.2 at indexes 1, 3"
+            )
+        );
+    }
+
+    #[test]
+    fn contain_n_times_matcher_should_count_occurrences() {
+        assert!(contain_n_times(1, 2).match_value(&vec![1, 2, 1]));
+        assert!(contain_n_times(3, 0).match_value(&vec![1, 2, 1]));
+        assert_eq!(
+            contain_n_times(1, 1).match_result(&vec![1, 2, 1]),
+            MatchResult::mismatched("element 1 appeared 2 times, at indexes 0, 2")
+        );
+        assert_eq!(
+            contain_n_times(3, 1).match_result(&vec![1, 2, 1]),
+            MatchResult::mismatched("element 3 was missing")
+        );
+    }
+
+    #[test]
+    fn contain_n_times_matcher_should_describe_itself() {
+        let description = contain_n_times(1, 2).description(&vec![1]);
+        assert_eq!(description.verb, String::from("contain"));
+        assert_eq!(description.object, Some(String::from("1 exactly 2 times")));
+    }
+
+    #[test]
+    fn collections_list_their_elements() {
+        assert_eq!([1, 2].elements(), vec![&1, &2]);
+        assert_eq!(vec![1, 2].elements(), vec![&1, &2]);
+        assert_eq!(
+            std::collections::BTreeSet::from([2, 1]).elements(),
+            vec![&1, &2]
+        );
+    }
+
+    #[test]
+    fn arrays_are_containers() {
+        assert!([1, 2, 3].contains_element(&2));
+        assert!(![1, 2, 3].contains_element(&4));
+    }
+
+    #[test]
+    fn arrays_are_emptiable() {
+        assert!(([] as [u64; 0]).empty());
+        assert!(![1, 2, 3].empty());
+    }
+
+    #[test]
+    fn vecs_are_containers() {
+        assert!(vec![1, 2, 3].contains_element(&2));
+        assert!(!vec![1, 2, 3].contains_element(&4));
+    }
+
+    #[test]
+    fn vecs_are_emptiable() {
+        assert!(std::vec::Vec::<i32>::new().empty());
+        assert!(!vec![1, 2, 3].empty());
+    }
+
+    #[test]
+    fn vecdeques_are_containers() {
+        let mut numbers = std::collections::VecDeque::new();
+        numbers.push_back(1);
+        numbers.push_back(2);
+        numbers.push_back(3);
+
+        assert!(numbers.contains_element(&2));
+        assert!(!numbers.contains_element(&4));
+    }
+
+    #[test]
+    fn vecdeques_are_emptiable() {
+        let mut numbers = std::collections::VecDeque::new();
+        assert!(numbers.empty());
+
+        numbers.push_back(42);
+        assert!(!numbers.empty());
+    }
+
+    #[test]
+    fn linkedlists_are_containers() {
+        let mut numbers = std::collections::LinkedList::new();
+        numbers.push_back(1);
+        numbers.push_back(2);
+        numbers.push_back(3);
+
+        assert!(numbers.contains_element(&2));
+        assert!(!numbers.contains_element(&4));
+    }
+
+    #[test]
+    fn linkedlists_are_emptiable() {
+        let mut numbers = std::collections::LinkedList::new();
+        assert!(numbers.empty());
+
+        numbers.push_back(42);
+        assert!(!numbers.empty())
+    }
+
+    #[test]
+    fn hashsets_are_containers() {
+        let mut numbers = std::collections::HashSet::new();
+        numbers.insert(1);
+        numbers.insert(2);
+        numbers.insert(3);
+
+        assert!(numbers.contains_element(&2));
+        assert!(!numbers.contains_element(&4));
+    }
+
+    #[test]
+    fn hashsets_are_emptiable() {
+        let mut numbers = std::collections::HashSet::new();
+        assert!(numbers.empty());
+
+        numbers.insert(42);
+        assert!(!numbers.empty())
+    }
+
+    #[test]
+    fn btreesets_are_containers() {
+        let mut numbers = std::collections::BTreeSet::new();
+        numbers.insert(1);
+        numbers.insert(2);
+        numbers.insert(3);
+
+        assert!(numbers.contains_element(&2));
+        assert!(!numbers.contains_element(&4));
+    }
+
+    #[test]
+    fn btreesets_are_emptiable() {
+        let mut numbers = std::collections::BTreeSet::new();
+        assert!(numbers.empty());
+
+        numbers.insert(42);
+        assert!(!numbers.empty())
+    }
+
+    #[test]
+    fn collections_have_a_length() {
+        assert_eq!([1, 2, 3].length(), 3);
+        assert_eq!(vec![1, 2, 3].length(), 3);
+        assert_eq!([1, 2, 3][..].length(), 3);
+        assert_eq!(std::collections::VecDeque::from([1, 2]).length(), 2);
+        assert_eq!(std::collections::LinkedList::from([1, 2]).length(), 2);
+        assert_eq!(std::collections::HashSet::from([1, 1]).length(), 1);
+        assert_eq!(std::collections::BTreeSet::from([1, 2]).length(), 2);
+    }
+
+    #[test]
+    fn maps_are_emptiable() {
+        assert!(std::collections::HashMap::<u32, u32>::new().empty());
+        assert!(!std::collections::BTreeMap::from([(1, 2)]).empty());
+    }
+
+    #[test]
+    fn maps_have_a_length() {
+        assert_eq!(
+            std::collections::HashMap::from([(1, 2), (3, 4)]).length(),
+            2
+        );
+        assert_eq!(std::collections::BTreeMap::from([(1, 2)]).length(), 1);
+    }
+
+    #[test]
+    fn strings_have_a_length_in_bytes() {
+        assert_eq!("foo".length(), 3);
+        assert_eq!(String::from("héllo").length(), 6);
     }
 }
@@ -0,0 +1,734 @@
+use crate::{Description, MatchResult, Matcher};
+
+#[cfg(feature = "derive")]
+pub use expect_derive::ApproxEq;
+
+/// Matches if the actual value is close to `expected`.
+///
+/// By default, floating-point numbers are close if they are at most 4 [ULPs] apart, or at most the
+/// machine epsilon of their type apart. ULPs alone would be far too strict near zero, where
+/// representable numbers are packed tightly: `0.1 + 0.2 - 0.3` is about `5.6e-17`, billions of
+/// ULPs away from `0.0`, yet still close to it by default. Use [`within`], [`relative`] or
+/// [`ulps`] to set a different tolerance. `NaN` is never close to anything, and infinities are
+/// only close to themselves.
+///
+/// Besides `f32` and `f64`, any type implementing [`ApproxEq`] is supported, including
+/// collections and tuples of floats, which are compared element by element. With the `derive`
+/// feature enabled, `ApproxEq` can be derived for structs.
+///
+/// [ULPs]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
+/// [`within`]: struct.BeCloseToMatcher.html#method.within
+/// [`relative`]: struct.BeCloseToMatcher.html#method.relative
+/// [`ulps`]: struct.BeCloseToMatcher.html#method.ulps
+/// [`ApproxEq`]: trait.ApproxEq.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::float::be_close_to};
+/// expect(&(0.1 + 0.2)).to(be_close_to(0.3));
+/// expect(&(0.1 + 0.2 - 0.3)).to(be_close_to(0.0));
+/// expect(&3.14159).to(be_close_to(3.14).within(0.01));
+/// expect(&101.0).to(be_close_to(100.0).relative(0.01));
+/// expect(&1.0f32).not_to(be_close_to(1.1).ulps(10));
+/// expect(&vec![0.1 + 0.2, 1.0]).to(be_close_to(vec![0.3, 1.0]));
+/// expect(&(1.0, [2.0, 3.0])).not_to(be_close_to((1.0, [2.0, 3.5])).within(0.1));
+/// ```
+pub fn be_close_to<T>(expected: T) -> BeCloseToMatcher<T> {
+    BeCloseToMatcher {
+        expected,
+        tolerance: Tolerance::UlpsOrEpsilon(DEFAULT_ULPS),
+    }
+}
+
+const DEFAULT_ULPS: u64 = 4;
+
+pub struct BeCloseToMatcher<T> {
+    expected: T,
+    tolerance: Tolerance,
+}
+
+/// How far apart two floating-point numbers can be while still being considered close.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// The numbers can differ by at most this amount.
+    Absolute(f64),
+    /// The numbers can differ by at most this fraction of the largest of their absolute values.
+    Relative(f64),
+    /// There can be at most this many representable numbers between them.
+    Ulps(u64),
+    /// There can be at most this many representable numbers between them, or they can differ by
+    /// at most the machine epsilon of their type. This is the default.
+    UlpsOrEpsilon(u64),
+}
+
+impl<T> BeCloseToMatcher<T> {
+    /// Sets an absolute tolerance: the values are close if their difference is at most `tolerance`.
+    pub fn within(self, tolerance: f64) -> Self {
+        BeCloseToMatcher {
+            tolerance: Tolerance::Absolute(tolerance),
+            ..self
+        }
+    }
+
+    /// Sets a relative tolerance: the values are close if their difference is at most `tolerance`
+    /// times the largest of their absolute values.
+    pub fn relative(self, tolerance: f64) -> Self {
+        BeCloseToMatcher {
+            tolerance: Tolerance::Relative(tolerance),
+            ..self
+        }
+    }
+
+    /// Sets a tolerance in [ULPs]: the values are close if there are at most `tolerance`
+    /// representable values between them.
+    ///
+    /// [ULPs]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
+    pub fn ulps(self, tolerance: u64) -> Self {
+        BeCloseToMatcher {
+            tolerance: Tolerance::Ulps(tolerance),
+            ..self
+        }
+    }
+}
+
+impl<T: ApproxEq + std::fmt::Debug> Matcher<T> for BeCloseToMatcher<T> {
+    fn match_value(&self, actual: &T) -> bool {
+        actual.approx_eq(&self.expected, self.tolerance).is_ok()
+    }
+
+    fn match_result(&self, actual: &T) -> MatchResult {
+        match actual.approx_eq(&self.expected, self.tolerance) {
+            Ok(()) => MatchResult::Matched,
+            Err(mismatch) => MatchResult::mismatched(mismatch.to_string()),
+        }
+    }
+
+    fn description(&self, _: &T) -> Description {
+        let tolerance = match self.tolerance {
+            Tolerance::Absolute(tolerance) => format!("within {:?}", tolerance),
+            Tolerance::Relative(tolerance) => format!("within {:?} relative", tolerance),
+            Tolerance::Ulps(tolerance) => format!("within {} ULPs", tolerance),
+            Tolerance::UlpsOrEpsilon(tolerance) => format!("within {} ULPs or epsilon", tolerance),
+        };
+        Description {
+            verb: String::from("be close to"),
+            object: Some(format!("{:?} ({})", self.expected, tolerance)),
+        }
+    }
+}
+
+/// Approximate equality, as checked by [`be_close_to`].
+///
+/// It's implemented for `f32`, `f64`, and for slices, arrays, [`Vec`]s, [`VecDeque`]s, [`Option`]s
+/// and tuples of types implementing it. With the `derive` feature enabled, it can be derived for
+/// structs, whose fields are then compared one by one.
+///
+/// [`be_close_to`]: fn.be_close_to.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`VecDeque`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html
+/// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+pub trait ApproxEq {
+    /// Should return an [`ApproxMismatch`] pointing at the first part of `self` that is not within
+    /// `tolerance` of `expected`.
+    ///
+    /// [`ApproxMismatch`]: struct.ApproxMismatch.html
+    fn approx_eq(&self, expected: &Self, tolerance: Tolerance) -> Result<(), ApproxMismatch>;
+}
+
+/// Where and why two values are not approximately equal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApproxMismatch {
+    path: String,
+    values: Option<(String, String)>,
+    reason: String,
+}
+
+impl ApproxMismatch {
+    /// Creates a mismatch at the top level of the compared values.
+    pub fn new<S: Into<String>>(reason: S) -> Self {
+        ApproxMismatch {
+            path: String::new(),
+            values: None,
+            reason: reason.into(),
+        }
+    }
+
+    /// Records the actual and expected values found where the mismatch occurred.
+    pub fn with_values<A: std::fmt::Debug, E: std::fmt::Debug>(
+        self,
+        actual: A,
+        expected: E,
+    ) -> Self {
+        ApproxMismatch {
+            values: Some((format!("{:?}", actual), format!("{:?}", expected))),
+            ..self
+        }
+    }
+
+    /// Moves the mismatch inside the element at `index`.
+    pub fn at_index(self, index: usize) -> Self {
+        ApproxMismatch {
+            path: format!("[{}]{}", index, self.path),
+            ..self
+        }
+    }
+
+    /// Moves the mismatch inside the field called `field`.
+    pub fn at_field(self, field: &str) -> Self {
+        ApproxMismatch {
+            path: format!(".{}{}", field, self.path),
+            ..self
+        }
+    }
+
+    /// The path to the mismatch, like `[2].x`, or an empty string at the top level.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Why the values are not approximately equal.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl std::fmt::Display for ApproxMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            return f.write_str(&self.reason);
+        }
+        write!(f, "at {}", self.path)?;
+        if let Some((actual, expected)) = &self.values {
+            write!(f, " (was {}, expected {})", actual, expected)?;
+        }
+        write!(f, ": {}", self.reason)
+    }
+}
+
+fn approx_eq_float<T: Float>(
+    actual: T,
+    expected: T,
+    tolerance: Tolerance,
+) -> Result<(), ApproxMismatch> {
+    if actual.is_nan() || expected.is_nan() {
+        return Err(
+            ApproxMismatch::new("NaN is never close to anything").with_values(actual, expected)
+        );
+    }
+    if actual.to_f64() == expected.to_f64() {
+        return Ok(());
+    }
+    if actual.is_infinite() || expected.is_infinite() {
+        return Err(
+            ApproxMismatch::new("infinity is only close to itself").with_values(actual, expected)
+        );
+    }
+
+    let delta = (actual.to_f64() - expected.to_f64()).abs();
+    let (close, distance, allowed) = match tolerance {
+        Tolerance::Absolute(tolerance) => (
+            delta <= tolerance,
+            format!("delta was {:?}", delta),
+            format!("absolute tolerance is {:?}", tolerance),
+        ),
+        Tolerance::Relative(tolerance) => {
+            let scale = actual.to_f64().abs().max(expected.to_f64().abs());
+            (
+                delta <= tolerance * scale,
+                format!("relative delta was {:?}", delta / scale),
+                format!("relative tolerance is {:?}", tolerance),
+            )
+        }
+        Tolerance::Ulps(tolerance) => {
+            let ulps = actual.ulps_between(expected);
+            (
+                ulps <= tolerance,
+                format!("was {} ULPs away", ulps),
+                format!("tolerance is {} ULPs", tolerance),
+            )
+        }
+        Tolerance::UlpsOrEpsilon(tolerance) => {
+            let ulps = actual.ulps_between(expected);
+            (
+                ulps <= tolerance || delta <= T::epsilon(),
+                format!("was {} ULPs away and delta was {:?}", ulps, delta),
+                format!(
+                    "tolerance is {} ULPs or epsilon ({:?})",
+                    tolerance,
+                    T::epsilon()
+                ),
+            )
+        }
+    };
+
+    if close {
+        Ok(())
+    } else {
+        Err(
+            ApproxMismatch::new(format!("{}, but {}", distance, allowed))
+                .with_values(actual, expected),
+        )
+    }
+}
+
+impl ApproxEq for f32 {
+    fn approx_eq(&self, expected: &Self, tolerance: Tolerance) -> Result<(), ApproxMismatch> {
+        approx_eq_float(*self, *expected, tolerance)
+    }
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(&self, expected: &Self, tolerance: Tolerance) -> Result<(), ApproxMismatch> {
+        approx_eq_float(*self, *expected, tolerance)
+    }
+}
+
+fn approx_eq_elements<'a, T: ApproxEq + 'a>(
+    actual: impl ExactSizeIterator<Item = &'a T>,
+    expected: impl ExactSizeIterator<Item = &'a T>,
+    tolerance: Tolerance,
+) -> Result<(), ApproxMismatch> {
+    if actual.len() != expected.len() {
+        return Err(ApproxMismatch::new(format!(
+            "length was {}, expected {}",
+            actual.len(),
+            expected.len()
+        )));
+    }
+    for (index, (actual, expected)) in actual.zip(expected).enumerate() {
+        actual
+            .approx_eq(expected, tolerance)
+            .map_err(|mismatch| mismatch.at_index(index))?;
+    }
+    Ok(())
+}
+
+impl<T: ApproxEq> ApproxEq for [T] {
+    fn approx_eq(&self, expected: &Self, tolerance: Tolerance) -> Result<(), ApproxMismatch> {
+        approx_eq_elements(self.iter(), expected.iter(), tolerance)
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    fn approx_eq(&self, expected: &Self, tolerance: Tolerance) -> Result<(), ApproxMismatch> {
+        approx_eq_elements(self.iter(), expected.iter(), tolerance)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for std::vec::Vec<T> {
+    fn approx_eq(&self, expected: &Self, tolerance: Tolerance) -> Result<(), ApproxMismatch> {
+        approx_eq_elements(self.iter(), expected.iter(), tolerance)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for std::collections::VecDeque<T> {
+    fn approx_eq(&self, expected: &Self, tolerance: Tolerance) -> Result<(), ApproxMismatch> {
+        approx_eq_elements(self.iter(), expected.iter(), tolerance)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Option<T> {
+    fn approx_eq(&self, expected: &Self, tolerance: Tolerance) -> Result<(), ApproxMismatch> {
+        match (self, expected) {
+            (Some(actual), Some(expected)) => actual.approx_eq(expected, tolerance),
+            (None, None) => Ok(()),
+            (Some(_), None) => Err(ApproxMismatch::new("was a Some, expected None")),
+            (None, Some(_)) => Err(ApproxMismatch::new("was None, expected a Some")),
+        }
+    }
+}
+
+impl<T: ApproxEq + ?Sized> ApproxEq for &T {
+    fn approx_eq(&self, expected: &Self, tolerance: Tolerance) -> Result<(), ApproxMismatch> {
+        (**self).approx_eq(*expected, tolerance)
+    }
+}
+
+macro_rules! impl_approx_eq_for_tuple {
+    ($($element:ident: $index:tt),+) => {
+        impl<$($element: ApproxEq),+> ApproxEq for ($($element,)+) {
+            fn approx_eq(&self, expected: &Self, tolerance: Tolerance) -> Result<(), ApproxMismatch> {
+                $(
+                    self.$index
+                        .approx_eq(&expected.$index, tolerance)
+                        .map_err(|mismatch| mismatch.at_field(stringify!($index)))?;
+                )+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_approx_eq_for_tuple!(A: 0);
+impl_approx_eq_for_tuple!(A: 0, B: 1);
+impl_approx_eq_for_tuple!(A: 0, B: 1, C: 2);
+impl_approx_eq_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_approx_eq_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_approx_eq_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
+/// Matches if the actual value is `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::float::be_nan};
+/// expect(&f64::NAN).to(be_nan());
+/// expect(&1.0).not_to(be_nan());
+/// ```
+pub fn be_nan() -> BeNanMatcher {
+    BeNanMatcher {}
+}
+
+pub struct BeNanMatcher {}
+
+impl<T: Float> Matcher<T> for BeNanMatcher {
+    fn match_value(&self, actual: &T) -> bool {
+        actual.is_nan()
+    }
+
+    fn description(&self, _: &T) -> Description {
+        Description {
+            verb: String::from("be NaN"),
+            object: None,
+        }
+    }
+}
+
+/// Matches if the actual value is neither infinite nor `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::float::be_finite};
+/// expect(&1.0).to(be_finite());
+/// expect(&f64::INFINITY).not_to(be_finite());
+/// ```
+pub fn be_finite() -> BeFiniteMatcher {
+    BeFiniteMatcher {}
+}
+
+pub struct BeFiniteMatcher {}
+
+impl<T: Float> Matcher<T> for BeFiniteMatcher {
+    fn match_value(&self, actual: &T) -> bool {
+        actual.is_finite()
+    }
+
+    fn description(&self, _: &T) -> Description {
+        Description {
+            verb: String::from("be finite"),
+            object: None,
+        }
+    }
+}
+
+/// Matches if the actual value is positive or negative infinity.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::float::be_infinite};
+/// expect(&f32::NEG_INFINITY).to(be_infinite());
+/// expect(&f32::NAN).not_to(be_infinite());
+/// ```
+pub fn be_infinite() -> BeInfiniteMatcher {
+    BeInfiniteMatcher {}
+}
+
+pub struct BeInfiniteMatcher {}
+
+impl<T: Float> Matcher<T> for BeInfiniteMatcher {
+    fn match_value(&self, actual: &T) -> bool {
+        actual.is_infinite()
+    }
+
+    fn description(&self, _: &T) -> Description {
+        Description {
+            verb: String::from("be infinite"),
+            object: None,
+        }
+    }
+}
+
+/// The floating-point types supported by the float matchers.
+pub trait Float: Copy + std::fmt::Debug {
+    fn to_f64(self) -> f64;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn is_infinite(self) -> bool;
+    /// Returns the number of representable values between `self` and `other`.
+    fn ulps_between(self, other: Self) -> u64;
+    /// Returns the difference between `1.0` and the next representable value.
+    fn epsilon() -> f64;
+}
+
+impl Float for f32 {
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+
+    fn is_infinite(self) -> bool {
+        f32::is_infinite(self)
+    }
+
+    fn ulps_between(self, other: Self) -> u64 {
+        // Maps the bits to integers that are ordered like the floats they represent.
+        let ordered = |value: f32| {
+            let bits = value.to_bits();
+            if bits & 0x8000_0000 != 0 {
+                !bits
+            } else {
+                bits | 0x8000_0000
+            }
+        };
+        u64::from(ordered(self).abs_diff(ordered(other)))
+    }
+
+    fn epsilon() -> f64 {
+        f64::from(f32::EPSILON)
+    }
+}
+
+impl Float for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+
+    fn is_infinite(self) -> bool {
+        f64::is_infinite(self)
+    }
+
+    fn ulps_between(self, other: Self) -> u64 {
+        // Maps the bits to integers that are ordered like the floats they represent.
+        let ordered = |value: f64| {
+            let bits = value.to_bits();
+            if bits & 0x8000_0000_0000_0000 != 0 {
+                !bits
+            } else {
+                bits | 0x8000_0000_0000_0000
+            }
+        };
+        ordered(self).abs_diff(ordered(other))
+    }
+
+    fn epsilon() -> f64 {
+        f64::EPSILON
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{be_close_to, be_finite, be_infinite, be_nan, ApproxEq, ApproxMismatch, Float};
+    use crate::{MatchResult, Matcher};
+
+    #[test]
+    fn be_close_to_matcher_should_match_values_a_few_ulps_apart_by_default() {
+        assert!(be_close_to(0.3).match_value(&(0.1 + 0.2)));
+        assert!(!be_close_to(0.3).match_value(&0.30001));
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_match_values_near_zero_within_epsilon_by_default() {
+        assert!(be_close_to(0.0).match_value(&(0.1 + 0.2 - 0.3)));
+        assert!(be_close_to(0.0f32).match_value(&(0.1f32 + 0.2 - 0.3)));
+        assert!(!be_close_to(0.0).ulps(4).match_value(&(0.1 + 0.2 - 0.3)));
+        assert_eq!(
+            be_close_to(0.0).match_result(&1e-15),
+            MatchResult::mismatched(format!(
+                "was {} ULPs away and delta was 1e-15, but tolerance is 4 ULPs or epsilon ({:?})",
+                1e-15f64.to_bits(),
+                f64::EPSILON
+            ))
+        );
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_support_absolute_tolerances() {
+        assert!(be_close_to(1.0).within(0.1).match_value(&1.05));
+        assert!(!be_close_to(1.0).within(0.1).match_value(&1.2));
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_support_relative_tolerances() {
+        assert!(be_close_to(1000.0).relative(0.01).match_value(&1009.0));
+        assert!(!be_close_to(1.0).relative(0.01).match_value(&1.02));
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_support_ulp_tolerances() {
+        let next = f32::from_bits(1.0f32.to_bits() + 2);
+        assert!(be_close_to(1.0f32).ulps(2).match_value(&next));
+        assert!(!be_close_to(1.0f32).ulps(1).match_value(&next));
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_never_match_nan() {
+        assert!(!be_close_to(f64::NAN)
+            .within(f64::INFINITY)
+            .match_value(&f64::NAN))
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_match_equal_infinities() {
+        assert!(be_close_to(f64::INFINITY).match_value(&f64::INFINITY))
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_only_match_infinities_with_themselves() {
+        for (expected, actual) in [
+            (f64::NEG_INFINITY, f64::INFINITY),
+            (1.0, f64::INFINITY),
+            (f64::MAX, f64::INFINITY),
+            (f64::INFINITY, f64::MAX),
+            (f64::MIN, f64::NEG_INFINITY),
+        ] {
+            assert!(!be_close_to(expected).match_value(&actual));
+            assert!(!be_close_to(expected).ulps(u64::MAX).match_value(&actual));
+            assert!(!be_close_to(expected)
+                .within(f64::INFINITY)
+                .match_value(&actual));
+            assert!(!be_close_to(expected).relative(0.01).match_value(&actual));
+        }
+        assert_eq!(
+            be_close_to(1.0).relative(0.01).match_result(&f64::INFINITY),
+            MatchResult::mismatched("infinity is only close to itself")
+        );
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_explain_a_mismatch() {
+        assert_eq!(
+            be_close_to(1.0).within(0.1).match_result(&1.5),
+            MatchResult::mismatched("delta was 0.5, but absolute tolerance is 0.1")
+        );
+        assert_eq!(
+            be_close_to(1.0f32)
+                .ulps(1)
+                .match_result(&f32::from_bits(1.0f32.to_bits() + 3)),
+            MatchResult::mismatched("was 3 ULPs away, but tolerance is 1 ULPs")
+        );
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_describe_itself() {
+        let description = be_close_to(1.0).within(0.1).description(&1.0);
+        assert_eq!(description.verb, String::from("be close to"));
+        assert_eq!(description.object, Some(String::from("1.0 (within 0.1)")));
+    }
+
+    #[test]
+    fn be_nan_matcher_should_match_if_actual_is_nan() {
+        assert!(be_nan().match_value(&f64::NAN));
+        assert!(!be_nan().match_value(&f64::INFINITY));
+    }
+
+    #[test]
+    fn be_finite_matcher_should_match_if_actual_is_finite() {
+        assert!(be_finite().match_value(&1.0f32));
+        assert!(!be_finite().match_value(&f32::NAN));
+        assert!(!be_finite().match_value(&f32::INFINITY));
+    }
+
+    #[test]
+    fn be_infinite_matcher_should_match_if_actual_is_infinite() {
+        assert!(be_infinite().match_value(&f64::NEG_INFINITY));
+        assert!(!be_infinite().match_value(&1.0));
+    }
+
+    #[test]
+    fn ulps_between_should_count_representable_values() {
+        assert_eq!(1.0f64.ulps_between(1.0), 0);
+        assert_eq!(0.0f64.ulps_between(-0.0), 1);
+        assert_eq!(
+            f32::MIN_POSITIVE.ulps_between(-f32::MIN_POSITIVE),
+            0x0100_0001
+        );
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_compare_collections_element_by_element() {
+        assert!(be_close_to(vec![0.3, 1.0]).match_value(&vec![0.1 + 0.2, 1.0]));
+        assert!(!be_close_to([0.3, 1.0]).within(0.1).match_value(&[0.3, 1.2]));
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_report_the_first_index_out_of_tolerance() {
+        assert_eq!(
+            be_close_to(vec![1.0, 2.0, 3.0])
+                .within(0.1)
+                .match_result(&vec![1.0, 2.5, 3.5]),
+            MatchResult::mismatched(
+                "at [1] (was 2.5, expected 2.0): delta was 0.5, but absolute tolerance is 0.1"
+            )
+        )
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_report_length_mismatches() {
+        assert_eq!(
+            be_close_to(vec![1.0, 2.0]).match_result(&vec![1.0]),
+            MatchResult::mismatched("length was 1, expected 2")
+        )
+    }
+
+    #[test]
+    fn be_close_to_matcher_should_report_the_path_in_nested_structures() {
+        assert_eq!(
+            be_close_to((1.0, vec![(2.0, 3.0)]))
+                .within(0.1)
+                .match_result(&(1.0, vec![(2.0, 3.5)])),
+            MatchResult::mismatched(
+                "at .1[0].1 (was 3.5, expected 3.0): delta was 0.5, but absolute tolerance is 0.1"
+            )
+        )
+    }
+
+    #[test]
+    fn approx_eq_can_be_implemented_for_custom_types() {
+        struct Point {
+            x: f64,
+            y: f64,
+        }
+
+        impl ApproxEq for Point {
+            fn approx_eq(
+                &self,
+                expected: &Self,
+                tolerance: super::Tolerance,
+            ) -> Result<(), ApproxMismatch> {
+                self.x
+                    .approx_eq(&expected.x, tolerance)
+                    .map_err(|mismatch| mismatch.at_field("x"))?;
+                self.y
+                    .approx_eq(&expected.y, tolerance)
+                    .map_err(|mismatch| mismatch.at_field("y"))
+            }
+        }
+
+        let mismatch = Point { x: 1.0, y: 2.0 }
+            .approx_eq(&Point { x: 1.0, y: 3.0 }, super::Tolerance::Absolute(0.1))
+            .unwrap_err();
+        assert_eq!(mismatch.path(), ".y");
+        assert_eq!(
+            mismatch.reason(),
+            "delta was 1.0, but absolute tolerance is 0.1"
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use crate::{Description, MatchResult, Matcher};
+
+use std::marker::PhantomData;
+
+/// Matches if all the matchers in `matchers` match.
+///
+/// `matchers` can be a tuple of up to 8 matchers, possibly of different types, or an array of
+/// matchers of the same type.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{collection::{be_empty, contain}, logical::{all_of, not}}};
+/// expect(&vec![1, 2, 3]).to(all_of((not(be_empty()), contain(2))));
+/// expect(&vec![1, 2, 3]).not_to(all_of([contain(2), contain(4)]));
+/// ```
+pub fn all_of<L>(matchers: L) -> AllOfMatcher<L> {
+    AllOfMatcher { matchers }
+}
+
+pub struct AllOfMatcher<L> {
+    matchers: L,
+}
+
+impl<T, L: MatcherList<T>> Matcher<T> for AllOfMatcher<L> {
+    fn match_value(&self, actual: &T) -> bool {
+        self.matchers
+            .matchers()
+            .iter()
+            .all(|matcher| matcher.match_value(actual))
+    }
+
+    fn match_result(&self, actual: &T) -> MatchResult {
+        for matcher in self.matchers.matchers() {
+            if let MatchResult::Mismatched { reason } = matcher.match_result(actual) {
+                return MatchResult::Mismatched { reason };
+            }
+        }
+        MatchResult::Matched
+    }
+
+    fn description(&self, actual: &T) -> Description {
+        join(&self.matchers.matchers(), actual, "and")
+    }
+}
+
+/// Matches if at least one of the matchers in `matchers` matches.
+///
+/// `matchers` can be a tuple of up to 8 matchers, possibly of different types, or an array of
+/// matchers of the same type.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{collection::{be_empty, contain}, logical::any_of}};
+/// expect(&vec![1, 2, 3]).to(any_of((be_empty(), contain(2))));
+/// expect(&vec![1, 2, 3]).not_to(any_of([contain(4), contain(5)]));
+/// ```
+pub fn any_of<L>(matchers: L) -> AnyOfMatcher<L> {
+    AnyOfMatcher { matchers }
+}
+
+pub struct AnyOfMatcher<L> {
+    matchers: L,
+}
+
+impl<T, L: MatcherList<T>> Matcher<T> for AnyOfMatcher<L> {
+    fn match_value(&self, actual: &T) -> bool {
+        self.matchers
+            .matchers()
+            .iter()
+            .any(|matcher| matcher.match_value(actual))
+    }
+
+    fn match_result(&self, actual: &T) -> MatchResult {
+        let mut reasons = Vec::new();
+        for matcher in self.matchers.matchers() {
+            match matcher.match_result(actual) {
+                MatchResult::Matched => return MatchResult::Matched,
+                MatchResult::Mismatched { reason } => reasons.extend(reason),
+            }
+        }
+        MatchResult::Mismatched {
+            reason: if reasons.is_empty() {
+                None
+            } else {
+                Some(reasons.join("\n"))
+            },
+        }
+    }
+
+    fn description(&self, actual: &T) -> Description {
+        join(&self.matchers.matchers(), actual, "or")
+    }
+}
+
+/// Matches if `inner` doesn't match.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{collection::{be_empty, contain}, logical::not}};
+/// expect(&vec![1, 2, 3]).to(not(be_empty()));
+/// expect(&vec![1, 2, 3]).not_to(not(contain(2)));
+/// ```
+pub fn not<M>(inner: M) -> NotMatcher<M> {
+    NotMatcher { inner }
+}
+
+pub struct NotMatcher<M> {
+    inner: M,
+}
+
+impl<T, M: Matcher<T>> Matcher<T> for NotMatcher<M> {
+    fn match_value(&self, actual: &T) -> bool {
+        !self.inner.match_value(actual)
+    }
+
+    fn description(&self, actual: &T) -> Description {
+        let phrase = phrase(self.inner.description(actual));
+        Description {
+            verb: format!("not {}", phrase),
+            object: None,
+        }
+    }
+}
+
+/// Matches if both `left` and `right` match. Usually created through [`Matcher::and`].
+///
+/// [`Matcher::and`]: ../../trait.Matcher.html#method.and
+pub struct AndMatcher<L, R, T> {
+    left: L,
+    right: R,
+    actual: PhantomData<fn(&T)>,
+}
+
+impl<L, R, T> AndMatcher<L, R, T> {
+    pub(crate) fn new(left: L, right: R) -> Self {
+        AndMatcher {
+            left,
+            right,
+            actual: PhantomData,
+        }
+    }
+}
+
+impl<T, L: Matcher<T>, R: Matcher<T>> Matcher<T> for AndMatcher<L, R, T> {
+    fn match_value(&self, actual: &T) -> bool {
+        self.left.match_value(actual) && self.right.match_value(actual)
+    }
+
+    fn match_result(&self, actual: &T) -> MatchResult {
+        match self.left.match_result(actual) {
+            MatchResult::Matched => self.right.match_result(actual),
+            mismatch => mismatch,
+        }
+    }
+
+    fn description(&self, actual: &T) -> Description {
+        join(&[&self.left, &self.right], actual, "and")
+    }
+}
+
+/// Matches if either `left` or `right` matches. Usually created through [`Matcher::or`].
+///
+/// [`Matcher::or`]: ../../trait.Matcher.html#method.or
+pub struct OrMatcher<L, R, T> {
+    left: L,
+    right: R,
+    actual: PhantomData<fn(&T)>,
+}
+
+impl<L, R, T> OrMatcher<L, R, T> {
+    pub(crate) fn new(left: L, right: R) -> Self {
+        OrMatcher {
+            left,
+            right,
+            actual: PhantomData,
+        }
+    }
+}
+
+impl<T, L: Matcher<T>, R: Matcher<T>> Matcher<T> for OrMatcher<L, R, T> {
+    fn match_value(&self, actual: &T) -> bool {
+        self.left.match_value(actual) || self.right.match_value(actual)
+    }
+
+    fn match_result(&self, actual: &T) -> MatchResult {
+        let mut reasons = Vec::new();
+        for matcher in [&self.left as &dyn Matcher<T>, &self.right] {
+            match matcher.match_result(actual) {
+                MatchResult::Matched => return MatchResult::Matched,
+                MatchResult::Mismatched { reason } => reasons.extend(reason),
+            }
+        }
+        MatchResult::Mismatched {
+            reason: if reasons.is_empty() {
+                None
+            } else {
+                Some(reasons.join("\n"))
+            },
+        }
+    }
+
+    fn description(&self, actual: &T) -> Description {
+        join(&[&self.left, &self.right], actual, "or")
+    }
+}
+
+/// A list of matchers for the same type, as accepted by [`all_of`] and [`any_of`].
+///
+/// [`all_of`]: fn.all_of.html
+/// [`any_of`]: fn.any_of.html
+pub trait MatcherList<T> {
+    fn matchers(&self) -> Vec<&dyn Matcher<T>>;
+}
+
+impl<T, M: Matcher<T>, const N: usize> MatcherList<T> for [M; N] {
+    fn matchers(&self) -> Vec<&dyn Matcher<T>> {
+        self.iter()
+            .map(|matcher| matcher as &dyn Matcher<T>)
+            .collect()
+    }
+}
+
+macro_rules! impl_matcher_list_for_tuple {
+    ($($matcher:ident: $index:tt),+) => {
+        impl<T, $($matcher: Matcher<T>),+> MatcherList<T> for ($($matcher,)+) {
+            fn matchers(&self) -> Vec<&dyn Matcher<T>> {
+                vec![$(&self.$index),+]
+            }
+        }
+    };
+}
+
+impl_matcher_list_for_tuple!(A: 0);
+impl_matcher_list_for_tuple!(A: 0, B: 1);
+impl_matcher_list_for_tuple!(A: 0, B: 1, C: 2);
+impl_matcher_list_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_matcher_list_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_matcher_list_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_matcher_list_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_matcher_list_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
+/// Joins the descriptions of `matchers` with `conjunction`, in parentheses so that the combination
+/// reads unambiguously wherever it is embedded, e.g. `be a Some and (contain 2 or be empty)`.
+fn join<T>(matchers: &[&dyn Matcher<T>], actual: &T, conjunction: &str) -> Description {
+    let phrases: Vec<String> = matchers
+        .iter()
+        .map(|matcher| phrase(matcher.description(actual)))
+        .collect();
+    let joined = phrases.join(&format!(" {} ", conjunction));
+    Description {
+        verb: if phrases.len() > 1 {
+            format!("({})", joined)
+        } else {
+            joined
+        },
+        object: None,
+    }
+}
+
+/// Renders a description as a single phrase, e.g. `equal 42`.
+pub(crate) fn phrase(description: Description) -> String {
+    match description.object {
+        Some(object) => format!("{} {}", description.verb, object),
+        None => description.verb,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{all_of, any_of, not};
+    use crate::{
+        matchers::{
+            collection::{be_empty, contain},
+            equal,
+            option::match_some,
+            result::be_ok,
+        },
+        MatchResult, Matcher,
+    };
+
+    #[test]
+    fn all_of_matcher_should_match_if_all_matchers_match() {
+        assert!(all_of((contain(1), contain(2))).match_value(&vec![1, 2, 3]))
+    }
+
+    #[test]
+    fn all_of_matcher_should_not_match_if_any_matcher_does_not_match() {
+        assert!(!all_of((contain(1), contain(4))).match_value(&vec![1, 2, 3]))
+    }
+
+    #[test]
+    fn all_of_matcher_should_explain_the_first_mismatch() {
+        assert_eq!(
+            all_of([contain(1), contain(4), contain(5)]).match_result(&vec![1, 2, 3]),
+            MatchResult::mismatched("element 4 was missing")
+        )
+    }
+
+    #[test]
+    fn all_of_matcher_should_describe_itself() {
+        let description = all_of((contain(1), not(be_empty()))).description(&vec![1]);
+        assert_eq!(
+            description.verb,
+            String::from("(contain 1 and not be empty)")
+        );
+        assert_eq!(description.object, None);
+    }
+
+    #[test]
+    fn any_of_matcher_should_match_if_any_matcher_matches() {
+        assert!(any_of((be_empty(), contain(2))).match_value(&vec![1, 2, 3]))
+    }
+
+    #[test]
+    fn any_of_matcher_should_not_match_if_no_matcher_matches() {
+        assert!(!any_of((be_empty(), contain(4))).match_value(&vec![1, 2, 3]))
+    }
+
+    #[test]
+    fn any_of_matcher_should_describe_itself() {
+        let description = any_of((contain(2), be_empty())).description(&vec![1]);
+        assert_eq!(description.verb, String::from("(contain 2 or be empty)"));
+        assert_eq!(description.object, None);
+    }
+
+    #[test]
+    fn any_of_matcher_should_not_parenthesize_conjunctions_in_objects() {
+        let description = any_of((equal("salt and pepper"), equal("oil"))).description(&"salt");
+        assert_eq!(
+            description.verb,
+            String::from("(equal \"salt and pepper\" or equal \"oil\")")
+        );
+    }
+
+    #[test]
+    fn not_matcher_should_match_if_inner_matcher_does_not_match() {
+        assert!(not(equal(2)).match_value(&3))
+    }
+
+    #[test]
+    fn not_matcher_should_not_match_if_inner_matcher_matches() {
+        assert!(!not(equal(2)).match_value(&2))
+    }
+
+    #[test]
+    fn not_matcher_should_describe_itself() {
+        let description = not(any_of((equal(2), equal(3)))).description(&3);
+        assert_eq!(description.verb, String::from("not (equal 2 or equal 3)"));
+        assert_eq!(description.object, None);
+    }
+
+    #[test]
+    fn not_matcher_should_not_parenthesize_conjunctions_in_objects() {
+        let description = not(equal("salt and pepper")).description(&"salt");
+        assert_eq!(
+            description.verb,
+            String::from("not equal \"salt and pepper\"")
+        );
+    }
+
+    #[test]
+    fn and_matcher_should_match_if_both_matchers_match() {
+        assert!(contain(1).and(contain(2)).match_value(&vec![1, 2]));
+        assert!(!contain(1).and(contain(3)).match_value(&vec![1, 2]));
+    }
+
+    #[test]
+    fn and_matcher_should_explain_a_mismatch() {
+        assert_eq!(
+            be_ok()
+                .and(equal(Ok::<u32, &str>(1)))
+                .match_result(&Err("boom")),
+            MatchResult::mismatched("was an Err")
+        )
+    }
+
+    #[test]
+    fn or_matcher_should_match_if_either_matcher_matches() {
+        assert!(contain(1).or(contain(3)).match_value(&vec![1, 2]));
+        assert!(!contain(3).or(contain(4)).match_value(&vec![1, 2]));
+    }
+
+    #[test]
+    fn or_matcher_should_explain_both_mismatches() {
+        assert_eq!(
+            contain(3).or(contain(4)).match_result(&vec![1, 2]),
+            MatchResult::mismatched("element 3 was missing\nelement 4 was missing")
+        );
+        assert_eq!(
+            be_empty().or(contain(1)).match_result(&vec![1, 2]),
+            MatchResult::Matched
+        );
+    }
+
+    #[test]
+    fn combined_matchers_should_describe_themselves() {
+        let description =
+            match_some(contain(2).or(be_empty()).and(not(contain(3)))).description(&Some(vec![1]));
+        assert_eq!(
+            description.verb,
+            String::from("be a Some and ((contain 2 or be empty) and not contain 3)")
+        );
+        assert_eq!(description.object, None);
+    }
+}
@@ -0,0 +1,557 @@
+use crate::{
+    diff::{collection_diff, CollectionDiff, CollectionKind},
+    Description, MatchResult, Matcher,
+};
+
+use std::borrow::Borrow;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// How many of the closest keys are suggested when a key is missing.
+const CLOSEST_KEYS: usize = 3;
+
+/// How many keys are compared to a missing key when looking for the closest ones.
+const MAX_COMPARED_KEYS: usize = 100;
+
+/// Matches if `actual` has an entry for `key`.
+///
+/// Supports [`HashMap`]s and [`BTreeMap`]s. As with [`HashMap::get`], `key` can be any borrowed
+/// form of the map's keys, e.g. a `&str` for a map keyed by `String`. When the key is missing, the
+/// failure lists the keys that look the most like it, among the first hundred for large maps.
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+/// [`HashMap::get`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html#method.get
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::map::have_key};
+/// # use std::collections::{BTreeMap, HashMap};
+/// expect(&HashMap::from([("name", "Ada")])).to(have_key("name"));
+/// expect(&HashMap::from([(String::from("name"), "Ada")])).not_to(have_key("email"));
+/// expect(&BTreeMap::from([(1, "one")])).to(have_key(&1));
+/// ```
+pub fn have_key<Q: ?Sized>(key: &Q) -> HaveKeyMatcher<'_, Q> {
+    HaveKeyMatcher { key }
+}
+
+pub struct HaveKeyMatcher<'k, Q: ?Sized> {
+    key: &'k Q,
+}
+
+impl<Q: Debug + ?Sized, M: Lookup<Q>> Matcher<M> for HaveKeyMatcher<'_, Q>
+where
+    M::Key: Debug,
+{
+    fn match_value(&self, map: &M) -> bool {
+        map.value_of(self.key).is_some()
+    }
+
+    fn match_result(&self, map: &M) -> MatchResult {
+        if self.match_value(map) {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(missing_key(map, self.key))
+        }
+    }
+
+    fn description(&self, _: &M) -> Description {
+        Description {
+            verb: String::from("have key"),
+            object: Some(format!("{:?}", self.key)),
+        }
+    }
+}
+
+/// Matches if `actual` has an entry whose value is `value`.
+///
+/// Supports [`HashMap`]s and [`BTreeMap`]s.
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::map::have_value};
+/// # use std::collections::BTreeMap;
+/// expect(&BTreeMap::from([("name", "Ada")])).to(have_value("Ada"));
+/// expect(&BTreeMap::from([("name", "Ada")])).not_to(have_value("Grace"));
+/// ```
+pub fn have_value<V>(value: V) -> HaveValueMatcher<V> {
+    HaveValueMatcher { value }
+}
+
+pub struct HaveValueMatcher<V> {
+    value: V,
+}
+
+impl<V: PartialEq + Debug, M: Map<Value = V>> Matcher<M> for HaveValueMatcher<V> {
+    fn match_value(&self, map: &M) -> bool {
+        map.entries().iter().any(|(_, value)| *value == &self.value)
+    }
+
+    fn match_result(&self, map: &M) -> MatchResult {
+        if self.match_value(map) {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(format!("value {:?} was missing", self.value))
+        }
+    }
+
+    fn description(&self, _: &M) -> Description {
+        Description {
+            verb: String::from("have value"),
+            object: Some(format!("{:?}", self.value)),
+        }
+    }
+}
+
+/// Matches if `actual` has an entry for `key` whose value matches `inner`.
+///
+/// Supports [`HashMap`]s and [`BTreeMap`]s. As with [`have_key`], `key` can be any borrowed form of
+/// the map's keys. When the key is missing, the failure lists the keys that look the most like it.
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+/// [`have_key`]: fn.have_key.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{equal, map::have_entry, numeric::be_greater_than}};
+/// # use std::collections::HashMap;
+/// let ages = HashMap::from([("Ada", 36), ("Grace", 85)]);
+/// expect(&ages).to(have_entry("Ada", equal(36)));
+/// expect(&ages).not_to(have_entry("Ada", be_greater_than(40)));
+/// ```
+pub fn have_entry<Q: ?Sized, I>(key: &Q, inner: I) -> HaveEntryMatcher<'_, Q, I> {
+    HaveEntryMatcher { key, inner }
+}
+
+pub struct HaveEntryMatcher<'k, Q: ?Sized, I> {
+    key: &'k Q,
+    inner: I,
+}
+
+impl<Q: Debug + ?Sized, V: Debug, M: Lookup<Q, Value = V>, I: Matcher<V>> Matcher<M>
+    for HaveEntryMatcher<'_, Q, I>
+where
+    M::Key: Debug,
+{
+    fn match_value(&self, map: &M) -> bool {
+        match map.value_of(self.key) {
+            Some(value) => self.inner.match_value(value),
+            None => false,
+        }
+    }
+
+    fn match_result(&self, map: &M) -> MatchResult {
+        let value = match map.value_of(self.key) {
+            Some(value) => value,
+            None => return MatchResult::mismatched(missing_key(map, self.key)),
+        };
+        match self.inner.match_result(value) {
+            MatchResult::Matched => MatchResult::Matched,
+            MatchResult::Mismatched { reason } => {
+                let was = format!("value at key {:?} was {:?}", self.key, value);
+                MatchResult::mismatched(match reason {
+                    Some(reason) => format!("{}\n{}", was, reason),
+                    None => was,
+                })
+            }
+        }
+    }
+
+    fn description(&self, map: &M) -> Description {
+        // The inner matcher can only be described with a value at hand, and only the value at `key`
+        // is the one it's about.
+        match map.value_of(self.key) {
+            Some(value) => {
+                let inner_desc = self.inner.description(value);
+                Description {
+                    verb: format!(
+                        "have key {:?} with a value to {}",
+                        self.key, inner_desc.verb
+                    ),
+                    object: inner_desc.object,
+                }
+            }
+            None => Description {
+                verb: format!(
+                    "have key {:?} with a value to match the inner matcher",
+                    self.key
+                ),
+                object: None,
+            },
+        }
+    }
+}
+
+/// Matches if `actual` has the same entries as `expected`.
+///
+/// Supports [`HashMap`]s and [`BTreeMap`]s. Unlike [`equal`], a failure reports the keys only in
+/// one of the maps and the keys whose values differ, sorted by key, whatever the size of the maps.
+/// Keys are looked up in each map, so the report costs no more than comparing the maps.
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+/// [`equal`]: ../fn.equal.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::map::equal_map};
+/// # use std::collections::{BTreeMap, HashMap};
+/// let ages = HashMap::from([("Ada", 36), ("Grace", 85)]);
+/// expect(&ages).to(equal_map(HashMap::from([("Grace", 85), ("Ada", 36)])));
+/// expect(&BTreeMap::from([(1, "one")])).not_to(equal_map(BTreeMap::from([(1, "uno")])));
+/// ```
+pub fn equal_map<E>(expected: E) -> EqualMapMatcher<E> {
+    EqualMapMatcher { expected }
+}
+
+pub struct EqualMapMatcher<E> {
+    expected: E,
+}
+
+impl<K: Debug, V: PartialEq + Debug, E: Lookup<K, Key = K, Value = V> + Debug, M> Matcher<M>
+    for EqualMapMatcher<E>
+where
+    M: Lookup<K, Key = K, Value = V>,
+{
+    fn match_value(&self, map: &M) -> bool {
+        let expected = self.expected.entries();
+        expected.len() == map.entries().len()
+            && expected
+                .into_iter()
+                .all(|(key, value)| map.value_of(key) == Some(value))
+    }
+
+    fn match_result(&self, map: &M) -> MatchResult {
+        let mut diff = CollectionDiff::default();
+        for (key, value) in self.expected.entries() {
+            match map.value_of(key) {
+                None => diff
+                    .only_expected
+                    .push((format!("{:?}", key), Some(format!("{:?}", value)))),
+                Some(actual) if actual != value => diff.different.push((
+                    format!("{:?}", key),
+                    format!("{:?}", value),
+                    format!("{:?}", actual),
+                )),
+                Some(_) => {}
+            }
+        }
+        for (key, value) in map.entries() {
+            if self.expected.value_of(key).is_none() {
+                diff.only_actual
+                    .push((format!("{:?}", key), Some(format!("{:?}", value))));
+            }
+        }
+        if diff.is_empty() {
+            MatchResult::Matched
+        } else {
+            MatchResult::mismatched(collection_diff(CollectionKind::Map, diff))
+        }
+    }
+
+    fn description(&self, _: &M) -> Description {
+        Description {
+            verb: String::from("equal"),
+            object: Some(format!("{:?}", self.expected)),
+        }
+    }
+}
+
+fn missing_key<Q: Debug + ?Sized, M: Map>(map: &M, key: &Q) -> String
+where
+    M::Key: Debug,
+{
+    let key = format!("{:?}", key);
+    let entries = map.entries();
+    let mut keys: Vec<(usize, String)> = entries
+        .iter()
+        .take(MAX_COMPARED_KEYS)
+        .map(|(k, _)| format!("{:?}", k))
+        .map(|k| (levenshtein(&key, &k), k))
+        .collect();
+    if keys.is_empty() {
+        return format!("key {} was missing, and the map was empty", key);
+    }
+    keys.sort();
+    let closest: Vec<String> = keys
+        .into_iter()
+        .take(CLOSEST_KEYS)
+        .map(|(_, k)| k)
+        .collect();
+    if entries.len() > MAX_COMPARED_KEYS {
+        format!(
+            "key {} was missing, closest of the first {} of {} keys: {}",
+            key,
+            MAX_COMPARED_KEYS,
+            entries.len(),
+            closest.join(", ")
+        )
+    } else {
+        format!(
+            "key {} was missing, closest keys: {}",
+            key,
+            closest.join(", ")
+        )
+    }
+}
+
+/// The number of single-character insertions, deletions and substitutions needed to turn `a` into
+/// `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// A map whose entries can be visited one by one, as needed by [`have_key`], [`have_value`] and
+/// [`have_entry`].
+///
+/// [`have_key`]: fn.have_key.html
+/// [`have_value`]: fn.have_value.html
+/// [`have_entry`]: fn.have_entry.html
+pub trait Map {
+    type Key;
+    type Value;
+
+    fn entries(&self) -> Vec<(&Self::Key, &Self::Value)>;
+}
+
+/// A [`Map`] whose values can be looked up by `Q`, a borrowed form of its keys, as needed by
+/// [`have_key`] and [`have_entry`].
+///
+/// [`Map`]: trait.Map.html
+/// [`have_key`]: fn.have_key.html
+/// [`have_entry`]: fn.have_entry.html
+pub trait Lookup<Q: ?Sized>: Map {
+    fn value_of(&self, key: &Q) -> Option<&Self::Value>;
+}
+
+impl<K, V> Map for std::collections::HashMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn entries(&self) -> Vec<(&K, &V)> {
+        self.iter().collect()
+    }
+}
+
+impl<K: Eq + Hash + Borrow<Q>, V, Q: Eq + Hash + ?Sized> Lookup<Q>
+    for std::collections::HashMap<K, V>
+{
+    fn value_of(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<K, V> Map for std::collections::BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn entries(&self) -> Vec<(&K, &V)> {
+        self.iter().collect()
+    }
+}
+
+impl<K: Ord + Borrow<Q>, V, Q: Ord + ?Sized> Lookup<Q> for std::collections::BTreeMap<K, V> {
+    fn value_of(&self, key: &Q) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{equal_map, have_entry, have_key, have_value, levenshtein, Lookup, Map};
+    use crate::{
+        matchers::{equal, numeric::be_greater_than},
+        MatchResult, Matcher,
+    };
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn equal_map_matcher_should_match_maps_with_the_same_entries() {
+        let expected = HashMap::from([("a", 1), ("b", 2)]);
+        assert!(equal_map(expected.clone()).match_value(&HashMap::from([("b", 2), ("a", 1)])));
+        assert!(!equal_map(expected.clone()).match_value(&HashMap::from([("a", 1)])));
+        assert!(!equal_map(expected).match_value(&HashMap::from([("a", 1), ("b", 3)])));
+    }
+
+    #[test]
+    fn equal_map_matcher_should_report_the_differing_entries() {
+        let expected = HashMap::from([(10, "ten"), (2, "two"), (3, "three")]);
+        let actual = HashMap::from([(3, "tres"), (4, "four"), (2, "two")]);
+
+        assert_eq!(
+            equal_map(expected).match_result(&actual),
+            MatchResult::mismatched(
+                "map diff (- expected, + actual):\n\
+                 keys only in expected:\n  10: \"ten\"\n\
+                 keys only in actual:\n  4: \"four\"\n\
+                 keys with different values:\n  3:\n    - \"three\"\n    + \"tres\"\n"
+            )
+        )
+    }
+
+    #[test]
+    fn equal_map_matcher_should_compare_keys_with_separators_in_their_rendering() {
+        let expected = BTreeMap::from([("a, b: c", 1)]);
+        let actual = BTreeMap::from([("a, b: c", 2)]);
+
+        assert_eq!(
+            equal_map(expected).match_result(&actual),
+            MatchResult::mismatched(
+                "map diff (- expected, + actual):\n\
+                 keys with different values:\n  \"a, b: c\":\n    - 1\n    + 2\n"
+            )
+        )
+    }
+
+    #[test]
+    fn have_key_matcher_should_match_if_key_is_present() {
+        assert!(have_key("name").match_value(&HashMap::from([("name", 1)])));
+        assert!(!have_key("email").match_value(&BTreeMap::from([("name", 1)])));
+    }
+
+    #[test]
+    fn have_key_matcher_should_look_up_borrowed_keys() {
+        let map = HashMap::from([(String::from("name"), 1)]);
+        assert!(have_key("name").match_value(&map));
+        assert!(have_entry("name", equal(1)).match_value(&map));
+        assert!(have_key(&1).match_value(&BTreeMap::from([(1, "one")])));
+    }
+
+    #[test]
+    fn have_key_matcher_should_suggest_the_closest_keys() {
+        let map = BTreeMap::from([("email", 1), ("id", 2), ("name", 3), ("names", 4)]);
+        assert_eq!(
+            have_key("nmae").match_result(&map),
+            MatchResult::mismatched(
+                "key \"nmae\" was missing, closest keys: \"name\", \"email\", \"names\""
+            )
+        );
+        assert_eq!(
+            have_key("name").match_result(&HashMap::<&str, u32>::new()),
+            MatchResult::mismatched("key \"name\" was missing, and the map was empty")
+        );
+    }
+
+    #[test]
+    fn have_key_matcher_should_only_compare_the_first_keys_of_large_maps() {
+        let map: BTreeMap<u32, u32> = (100..1100).map(|key| (key, key)).collect();
+        assert_eq!(
+            have_key(&99).match_result(&map),
+            MatchResult::mismatched(
+                "key 99 was missing, closest of the first 100 of 1000 keys: 199, 109, 119"
+            )
+        );
+    }
+
+    #[test]
+    fn have_key_matcher_should_describe_itself() {
+        let description = have_key("name").description(&HashMap::from([("id", 1)]));
+        assert_eq!(description.verb, String::from("have key"));
+        assert_eq!(description.object, Some(String::from("\"name\"")));
+    }
+
+    #[test]
+    fn have_value_matcher_should_match_if_value_is_present() {
+        assert!(have_value(1).match_value(&HashMap::from([("name", 1)])));
+        assert_eq!(
+            have_value(2).match_result(&BTreeMap::from([("name", 1)])),
+            MatchResult::mismatched("value 2 was missing")
+        );
+    }
+
+    #[test]
+    fn have_value_matcher_should_describe_itself() {
+        let description = have_value(1).description(&HashMap::from([("id", 1)]));
+        assert_eq!(description.verb, String::from("have value"));
+        assert_eq!(description.object, Some(String::from("1")));
+    }
+
+    #[test]
+    fn have_entry_matcher_should_match_the_value_at_key() {
+        let map = HashMap::from([("Ada", 36), ("Grace", 85)]);
+        assert!(have_entry("Ada", equal(36)).match_value(&map));
+        assert!(!have_entry("Ada", equal(85)).match_value(&map));
+        assert!(!have_entry("Alan", equal(36)).match_value(&map));
+    }
+
+    #[test]
+    fn have_entry_matcher_should_explain_a_mismatch() {
+        let map = BTreeMap::from([("Ada", 36), ("Grace", 85)]);
+        assert_eq!(
+            have_entry("Ada", be_greater_than(40)).match_result(&map),
+            MatchResult::mismatched("value at key \"Ada\" was 36")
+        );
+        assert_eq!(
+            have_entry("Adam", equal(36)).match_result(&map),
+            MatchResult::mismatched("key \"Adam\" was missing, closest keys: \"Ada\", \"Grace\"")
+        );
+    }
+
+    #[test]
+    fn have_entry_matcher_should_describe_itself() {
+        let description =
+            have_entry("Ada", be_greater_than(40)).description(&HashMap::from([("Ada", 36)]));
+        assert_eq!(
+            description.verb,
+            String::from("have key \"Ada\" with a value to be greater than")
+        );
+        assert_eq!(description.object, Some(String::from("40")));
+    }
+
+    #[test]
+    fn have_entry_matcher_should_describe_itself_when_the_key_is_missing() {
+        let expected =
+            have_entry("Ada", be_greater_than(40)).description(&HashMap::<&str, u32>::new());
+        assert_eq!(
+            expected.verb,
+            String::from("have key \"Ada\" with a value to match the inner matcher")
+        );
+        assert_eq!(expected.object, None);
+        assert_eq!(
+            have_entry("Ada", be_greater_than(40))
+                .description(&HashMap::from([("Grace", 85)]))
+                .verb,
+            expected.verb
+        );
+    }
+
+    #[test]
+    fn should_compute_levenshtein_distances() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("name", "name"), 0);
+    }
+
+    #[test]
+    fn hashmaps_are_maps() {
+        let map = HashMap::from([(1, "one")]);
+        assert_eq!(map.value_of(&1), Some(&"one"));
+        assert_eq!(map.value_of(&2), None);
+        assert_eq!(map.entries(), vec![(&1, &"one")]);
+    }
+
+    #[test]
+    fn btreemaps_are_maps() {
+        let map = BTreeMap::from([(2, "two"), (1, "one")]);
+        assert_eq!(map.value_of(&1), Some(&"one"));
+        assert_eq!(map.entries(), vec![(&1, &"one"), (&2, &"two")]);
+    }
+}
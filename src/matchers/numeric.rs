@@ -0,0 +1,416 @@
+use crate::{Description, Matcher};
+
+use std::ops::{Bound, RangeBounds};
+
+/// Matches if the actual value is greater than `expected`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::numeric::be_greater_than};
+/// expect(&4).to(be_greater_than(3));
+/// expect(&3).not_to(be_greater_than(3));
+/// ```
+pub fn be_greater_than<T>(expected: T) -> BeGreaterThanMatcher<T> {
+    BeGreaterThanMatcher { expected }
+}
+
+pub struct BeGreaterThanMatcher<T> {
+    expected: T,
+}
+
+impl<E: std::fmt::Debug, A: PartialOrd<E>> Matcher<A> for BeGreaterThanMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        actual > &self.expected
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("be greater than"),
+            object: Some(format!("{:?}", self.expected)),
+        }
+    }
+}
+
+/// Matches if the actual value is less than `expected`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::numeric::be_less_than};
+/// expect(&2).to(be_less_than(3));
+/// expect(&3).not_to(be_less_than(3));
+/// ```
+pub fn be_less_than<T>(expected: T) -> BeLessThanMatcher<T> {
+    BeLessThanMatcher { expected }
+}
+
+pub struct BeLessThanMatcher<T> {
+    expected: T,
+}
+
+impl<E: std::fmt::Debug, A: PartialOrd<E>> Matcher<A> for BeLessThanMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        actual < &self.expected
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("be less than"),
+            object: Some(format!("{:?}", self.expected)),
+        }
+    }
+}
+
+/// Matches if the actual value is greater than or equal to `expected`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::numeric::be_at_least};
+/// expect(&3).to(be_at_least(3));
+/// expect(&2).not_to(be_at_least(3));
+/// ```
+pub fn be_at_least<T>(expected: T) -> BeAtLeastMatcher<T> {
+    BeAtLeastMatcher { expected }
+}
+
+pub struct BeAtLeastMatcher<T> {
+    expected: T,
+}
+
+impl<E: std::fmt::Debug, A: PartialOrd<E>> Matcher<A> for BeAtLeastMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        actual >= &self.expected
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("be at least"),
+            object: Some(format!("{:?}", self.expected)),
+        }
+    }
+}
+
+/// Matches if the actual value is less than or equal to `expected`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::numeric::be_at_most};
+/// expect(&3).to(be_at_most(3));
+/// expect(&4).not_to(be_at_most(3));
+/// ```
+pub fn be_at_most<T>(expected: T) -> BeAtMostMatcher<T> {
+    BeAtMostMatcher { expected }
+}
+
+pub struct BeAtMostMatcher<T> {
+    expected: T,
+}
+
+impl<E: std::fmt::Debug, A: PartialOrd<E>> Matcher<A> for BeAtMostMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        actual <= &self.expected
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("be at most"),
+            object: Some(format!("{:?}", self.expected)),
+        }
+    }
+}
+
+/// Matches if the actual value is between `low` and `high`, both included.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::numeric::be_between};
+/// expect(&3).to(be_between(1, 3));
+/// expect(&4).not_to(be_between(1, 3));
+/// ```
+pub fn be_between<T>(low: T, high: T) -> BeBetweenMatcher<T> {
+    BeBetweenMatcher { low, high }
+}
+
+pub struct BeBetweenMatcher<T> {
+    low: T,
+    high: T,
+}
+
+impl<E: std::fmt::Debug, A: PartialOrd<E>> Matcher<A> for BeBetweenMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        actual >= &self.low && actual <= &self.high
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("be between"),
+            object: Some(format!("{:?} and {:?}", self.low, self.high)),
+        }
+    }
+}
+
+/// Matches if the actual value is contained in `range`.
+///
+/// Any kind of range is supported, including ranges with an excluded or unbounded end.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::numeric::be_in_range};
+/// expect(&3).to(be_in_range(1..=3));
+/// expect(&3).not_to(be_in_range(1..3));
+/// expect(&3.5).to(be_in_range(3.0..));
+/// ```
+pub fn be_in_range<R>(range: R) -> BeInRangeMatcher<R> {
+    BeInRangeMatcher { range }
+}
+
+pub struct BeInRangeMatcher<R> {
+    range: R,
+}
+
+impl<T: PartialOrd, R: RangeBounds<T> + std::fmt::Debug> Matcher<T> for BeInRangeMatcher<R> {
+    fn match_value(&self, actual: &T) -> bool {
+        let above_start = match self.range.start_bound() {
+            Bound::Included(start) => actual >= start,
+            Bound::Excluded(start) => actual > start,
+            Bound::Unbounded => true,
+        };
+        let below_end = match self.range.end_bound() {
+            Bound::Included(end) => actual <= end,
+            Bound::Excluded(end) => actual < end,
+            Bound::Unbounded => true,
+        };
+        above_start && below_end
+    }
+
+    fn description(&self, _: &T) -> Description {
+        Description {
+            verb: String::from("be in range"),
+            object: Some(format!("{:?}", self.range)),
+        }
+    }
+}
+
+/// Matches if the actual value is greater than zero, where zero is the type's [`Default`] value.
+///
+/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::numeric::be_positive};
+/// expect(&3).to(be_positive());
+/// expect(&0.0).not_to(be_positive());
+/// ```
+pub fn be_positive() -> BePositiveMatcher {
+    BePositiveMatcher {}
+}
+
+pub struct BePositiveMatcher {}
+
+impl<T: PartialOrd + Default> Matcher<T> for BePositiveMatcher {
+    fn match_value(&self, actual: &T) -> bool {
+        actual > &T::default()
+    }
+
+    fn description(&self, _: &T) -> Description {
+        Description {
+            verb: String::from("be positive"),
+            object: None,
+        }
+    }
+}
+
+/// Matches if the actual value is less than zero, where zero is the type's [`Default`] value.
+///
+/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::numeric::be_negative};
+/// expect(&-3).to(be_negative());
+/// expect(&0).not_to(be_negative());
+/// ```
+pub fn be_negative() -> BeNegativeMatcher {
+    BeNegativeMatcher {}
+}
+
+pub struct BeNegativeMatcher {}
+
+impl<T: PartialOrd + Default> Matcher<T> for BeNegativeMatcher {
+    fn match_value(&self, actual: &T) -> bool {
+        actual < &T::default()
+    }
+
+    fn description(&self, _: &T) -> Description {
+        Description {
+            verb: String::from("be negative"),
+            object: None,
+        }
+    }
+}
+
+/// Matches if the actual value is zero, where zero is the type's [`Default`] value.
+///
+/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::numeric::be_zero};
+/// expect(&0).to(be_zero());
+/// expect(&0.1).not_to(be_zero());
+/// ```
+pub fn be_zero() -> BeZeroMatcher {
+    BeZeroMatcher {}
+}
+
+pub struct BeZeroMatcher {}
+
+impl<T: PartialOrd + Default> Matcher<T> for BeZeroMatcher {
+    fn match_value(&self, actual: &T) -> bool {
+        actual == &T::default()
+    }
+
+    fn description(&self, _: &T) -> Description {
+        Description {
+            verb: String::from("be zero"),
+            object: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        be_at_least, be_at_most, be_between, be_greater_than, be_in_range, be_less_than,
+        be_negative, be_positive, be_zero,
+    };
+    use crate::Matcher;
+
+    #[test]
+    fn be_greater_than_matcher_should_match_if_actual_is_greater() {
+        assert!(be_greater_than(3).match_value(&4));
+        assert!(!be_greater_than(3).match_value(&3));
+    }
+
+    #[test]
+    fn be_greater_than_matcher_should_describe_itself() {
+        let description = be_greater_than(3).description(&4);
+        assert_eq!(description.verb, String::from("be greater than"));
+        assert_eq!(description.object, Some(String::from("3")));
+    }
+
+    #[test]
+    fn be_less_than_matcher_should_match_if_actual_is_less() {
+        assert!(be_less_than(3).match_value(&2));
+        assert!(!be_less_than(3).match_value(&3));
+    }
+
+    #[test]
+    fn be_less_than_matcher_should_describe_itself() {
+        let description = be_less_than(3).description(&2);
+        assert_eq!(description.verb, String::from("be less than"));
+        assert_eq!(description.object, Some(String::from("3")));
+    }
+
+    #[test]
+    fn be_at_least_matcher_should_match_if_actual_is_greater_or_equal() {
+        assert!(be_at_least(3).match_value(&3));
+        assert!(be_at_least(3).match_value(&4));
+        assert!(!be_at_least(3).match_value(&2));
+    }
+
+    #[test]
+    fn be_at_least_matcher_should_describe_itself() {
+        let description = be_at_least(3).description(&3);
+        assert_eq!(description.verb, String::from("be at least"));
+        assert_eq!(description.object, Some(String::from("3")));
+    }
+
+    #[test]
+    fn be_at_most_matcher_should_match_if_actual_is_less_or_equal() {
+        assert!(be_at_most(3).match_value(&3));
+        assert!(be_at_most(3).match_value(&2));
+        assert!(!be_at_most(3).match_value(&4));
+    }
+
+    #[test]
+    fn be_at_most_matcher_should_describe_itself() {
+        let description = be_at_most(3).description(&3);
+        assert_eq!(description.verb, String::from("be at most"));
+        assert_eq!(description.object, Some(String::from("3")));
+    }
+
+    #[test]
+    fn be_between_matcher_should_match_if_actual_is_between_bounds() {
+        assert!(be_between(1, 3).match_value(&1));
+        assert!(be_between(1, 3).match_value(&3));
+        assert!(!be_between(1, 3).match_value(&0));
+        assert!(!be_between(1, 3).match_value(&4));
+    }
+
+    #[test]
+    fn be_between_matcher_should_describe_itself() {
+        let description = be_between(1, 3).description(&2);
+        assert_eq!(description.verb, String::from("be between"));
+        assert_eq!(description.object, Some(String::from("1 and 3")));
+    }
+
+    #[test]
+    fn be_in_range_matcher_should_respect_range_bounds() {
+        assert!(be_in_range(1..3).match_value(&1));
+        assert!(!be_in_range(1..3).match_value(&3));
+        assert!(be_in_range(1..=3).match_value(&3));
+        assert!(be_in_range(..3).match_value(&-100));
+        assert!(be_in_range(1..).match_value(&100));
+        assert!(!be_in_range(1..).match_value(&0));
+    }
+
+    #[test]
+    fn be_in_range_matcher_should_describe_itself() {
+        let description = be_in_range(1..=3).description(&2);
+        assert_eq!(description.verb, String::from("be in range"));
+        assert_eq!(description.object, Some(String::from("1..=3")));
+    }
+
+    #[test]
+    fn be_positive_matcher_should_match_if_actual_is_greater_than_zero() {
+        assert!(be_positive().match_value(&1));
+        assert!(!be_positive().match_value(&0));
+        assert!(!be_positive().match_value(&-1.5));
+    }
+
+    #[test]
+    fn be_negative_matcher_should_match_if_actual_is_less_than_zero() {
+        assert!(be_negative().match_value(&-1));
+        assert!(!be_negative().match_value(&0));
+        assert!(!be_negative().match_value(&1.5));
+    }
+
+    #[test]
+    fn be_zero_matcher_should_match_if_actual_is_zero() {
+        assert!(be_zero().match_value(&0));
+        assert!(be_zero().match_value(&0.0));
+        assert!(!be_zero().match_value(&1));
+    }
+
+    #[test]
+    fn sign_matchers_should_describe_themselves() {
+        assert_eq!(
+            be_positive().description(&1).verb,
+            String::from("be positive")
+        );
+        assert_eq!(
+            be_negative().description(&1).verb,
+            String::from("be negative")
+        );
+        assert_eq!(be_zero().description(&1).verb, String::from("be zero"));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     matchers::{equal, EqualMatcher},
-    Description, Matcher,
+    Description, MatchResult, Matcher,
 };
 
 /// Matches if `actual` is an [`Option::Some`].
@@ -62,6 +62,13 @@ impl<T: std::fmt::Debug, M: Matcher<T>> Matcher<Option<T>> for MatchSomeMatcher<
         false
     }
 
+    fn match_result(&self, actual: &Option<T>) -> MatchResult {
+        if let Some(value) = actual {
+            return self.inner.match_result(value);
+        }
+        MatchResult::mismatched("was None")
+    }
+
     fn description(&self, actual: &Option<T>) -> Description {
         if let Some(value) = actual {
             let inner_desc = self.inner.description(value);
@@ -100,6 +107,13 @@ impl<T> Matcher<Option<T>> for NoneMatcher {
         actual.is_none()
     }
 
+    fn match_result(&self, actual: &Option<T>) -> MatchResult {
+        match actual {
+            None => MatchResult::Matched,
+            Some(_) => MatchResult::mismatched("was a Some"),
+        }
+    }
+
     fn description(&self, _: &Option<T>) -> Description {
         Description {
             verb: String::from("be None"),
@@ -127,7 +141,10 @@ pub fn equal_some<I>(inner: I) -> MatchSomeMatcher<EqualMatcher<I>> {
 #[cfg(test)]
 mod tests {
     use super::{be_none, be_some, match_some};
-    use crate::{matchers::equal, Matcher};
+    use crate::{
+        matchers::{equal, result::be_ok},
+        MatchResult, Matcher,
+    };
 
     #[test]
     fn some_matcher_should_match_if_actual_is_some() {
@@ -156,6 +173,20 @@ mod tests {
         assert!(!be_none().match_value(&Some("thing")))
     }
 
+    #[test]
+    fn none_matcher_should_explain_a_mismatch() {
+        assert_eq!(
+            be_none().match_result(&Some("thing")),
+            MatchResult::mismatched("was a Some")
+        )
+    }
+
+    #[test]
+    fn none_matcher_should_accept_values_without_debug() {
+        struct Opaque;
+        assert!(!be_none().match_value(&Some(Opaque)));
+    }
+
     #[test]
     fn none_matcher_should_describe_itself() {
         let description = be_none().description(&None::<&str>);
@@ -179,6 +210,22 @@ mod tests {
         assert!(!match_some(equal("foo")).match_value(&None::<&str>))
     }
 
+    #[test]
+    fn match_some_matcher_should_explain_a_mismatch_when_actual_is_not_some() {
+        assert_eq!(
+            match_some(equal("foo")).match_result(&None::<&str>),
+            MatchResult::mismatched("was None")
+        )
+    }
+
+    #[test]
+    fn match_some_matcher_should_explain_a_mismatch_with_its_inner_matcher() {
+        assert_eq!(
+            match_some(be_ok()).match_result(&Some(Err::<u32, &str>("boom"))),
+            MatchResult::mismatched("was an Err")
+        )
+    }
+
     #[test]
     fn match_some_matcher_should_describe_itself_when_actual_is_not_some() {
         let description = match_some(equal("foo")).description(&None::<&str>);
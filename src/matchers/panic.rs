@@ -0,0 +1,408 @@
+use crate::{Description, MatchResult, Matcher};
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+/// Wraps a function so that it can be used as the actual value of an expectation.
+///
+/// Closures don't implement [`Debug`], which [`expect`] needs in order to report failures. The
+/// wrapper renders as `<function>` instead.
+///
+/// [`Debug`]: https://doc.rust-lang.org/std/fmt/trait.Debug.html
+/// [`expect`]: ../../fn.expect.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::panic::{calling, panic}};
+/// expect(&calling(|| panic!("boom"))).to(panic());
+/// ```
+pub fn calling<F>(function: F) -> Calling<F> {
+    Calling { function }
+}
+
+pub struct Calling<F> {
+    function: F,
+}
+
+impl<F> std::fmt::Debug for Calling<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("<function>")
+    }
+}
+
+/// A function that can be checked by the panic matchers: either a [`Calling`] wrapper or anything
+/// implementing [`Fn`].
+///
+/// [`Calling`]: struct.Calling.html
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+pub trait Callable {
+    fn call(&self);
+}
+
+impl<F: Fn() -> R, R> Callable for F {
+    fn call(&self) {
+        self();
+    }
+}
+
+impl<F: Fn() -> R, R> Callable for Calling<F> {
+    fn call(&self) {
+        (self.function)();
+    }
+}
+
+thread_local! {
+    /// Whether the current thread is calling a function whose panics are expected.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Calls `callable`, returning the payload of its panic, if any. As with `#[should_panic]`, the
+/// panic isn't reported on stderr.
+///
+/// The panic hook is process-wide, so while `callable` runs it is replaced by one that stays quiet
+/// on this thread and defers to the previous hook everywhere else, then restored. A lock keeps
+/// other threads from swapping it meanwhile, so the panic matchers run one at a time, except when
+/// nested.
+fn catch_panic<C: Callable>(callable: &C) -> Option<Box<dyn Any + Send>> {
+    static HOOK: Mutex<()> = Mutex::new(());
+
+    if CATCHING.with(Cell::get) {
+        // The hook is already silenced by an enclosing call.
+        return catch_unwind(AssertUnwindSafe(|| callable.call())).err();
+    }
+
+    let _guard = HOOK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let previous = Arc::new(std::panic::take_hook());
+    let hook = Arc::clone(&previous);
+    std::panic::set_hook(Box::new(move |info| {
+        if !CATCHING.with(Cell::get) {
+            hook(info)
+        }
+    }));
+
+    CATCHING.with(|catching| catching.set(true));
+    let result = catch_unwind(AssertUnwindSafe(|| callable.call()));
+    CATCHING.with(|catching| catching.set(false));
+
+    drop(std::panic::take_hook());
+    match Arc::try_unwrap(previous) {
+        Ok(previous) => std::panic::set_hook(previous),
+        Err(previous) => std::panic::set_hook(Box::new(move |info| previous(info))),
+    }
+    result.err()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> Option<String> {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        Some(message.to_string())
+    } else {
+        payload.downcast_ref::<String>().cloned()
+    }
+}
+
+/// Matches if calling the actual function panics.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::panic::{calling, panic}};
+/// expect(&calling(|| panic!("boom"))).to(panic());
+/// expect(&calling(|| 42)).not_to(panic());
+/// ```
+pub fn panic() -> PanicMatcher {
+    PanicMatcher {}
+}
+
+pub struct PanicMatcher {}
+
+impl<C: Callable> Matcher<C> for PanicMatcher {
+    fn match_value(&self, actual: &C) -> bool {
+        catch_panic(actual).is_some()
+    }
+
+    fn match_result(&self, actual: &C) -> MatchResult {
+        match catch_panic(actual) {
+            Some(_) => MatchResult::Matched,
+            None => MatchResult::mismatched("did not panic"),
+        }
+    }
+
+    fn description(&self, _: &C) -> Description {
+        Description {
+            verb: String::from("panic"),
+            object: None,
+        }
+    }
+}
+
+/// Matches if calling the actual function panics with a message that matches the inner matcher.
+///
+/// The message is the panic payload, if it's a `&str` or a `String`, as is the case for payloads
+/// created by [`panic!`].
+///
+/// [`panic!`]: https://doc.rust-lang.org/std/macro.panic.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{equal, panic::{calling, panic_with_message},
+/// # string::match_regex}};
+/// expect(&calling(|| "".parse::<u32>().unwrap()))
+///     .to(panic_with_message(match_regex("Empty")));
+/// expect(&calling(|| panic!("boom"))).not_to(panic_with_message(equal("bang")));
+/// ```
+pub fn panic_with_message<M>(inner: M) -> PanicWithMessageMatcher<M> {
+    PanicWithMessageMatcher { inner }
+}
+
+pub struct PanicWithMessageMatcher<M> {
+    inner: M,
+}
+
+impl<C: Callable, M: Matcher<String>> Matcher<C> for PanicWithMessageMatcher<M> {
+    fn match_value(&self, actual: &C) -> bool {
+        self.match_result(actual).is_match()
+    }
+
+    fn match_result(&self, actual: &C) -> MatchResult {
+        let payload = match catch_panic(actual) {
+            Some(payload) => payload,
+            None => return MatchResult::mismatched("did not panic"),
+        };
+        let message = match panic_message(payload.as_ref()) {
+            Some(message) => message,
+            None => return MatchResult::mismatched("panicked with a non-string payload"),
+        };
+        match self.inner.match_result(&message) {
+            MatchResult::Matched => MatchResult::Matched,
+            MatchResult::Mismatched { reason } => {
+                let panicked = format!("panicked with message {:?}", message);
+                MatchResult::mismatched(match reason {
+                    Some(reason) => format!("{}\n{}", panicked, reason),
+                    None => panicked,
+                })
+            }
+        }
+    }
+
+    fn description(&self, _: &C) -> Description {
+        let inner_desc = self.inner.description(&String::new());
+        Description {
+            verb: format!("panic with a message to {}", inner_desc.verb),
+            object: inner_desc.object,
+        }
+    }
+}
+
+/// Matches if calling the actual function panics with a payload of type `P` that matches the
+/// inner matcher.
+///
+/// Useful with [`panic_any`], or whenever a panic payload isn't a message.
+///
+/// [`panic_any`]: https://doc.rust-lang.org/std/panic/fn.panic_any.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{equal, panic::{calling, panic_with_payload}}};
+/// let answer = calling(|| std::panic::panic_any(42u32));
+/// expect(&answer).to(panic_with_payload::<u32, _>(equal(42)));
+/// expect(&calling(|| panic!("boom"))).not_to(panic_with_payload::<u32, _>(equal(42)));
+/// ```
+pub fn panic_with_payload<P, M: Matcher<P>>(inner: M) -> PanicWithPayloadMatcher<P, M> {
+    PanicWithPayloadMatcher {
+        inner,
+        last_payload: RefCell::new(None),
+        payload: PhantomData,
+    }
+}
+
+pub struct PanicWithPayloadMatcher<P, M> {
+    inner: M,
+    // The inner matcher can only be described with a payload at hand, so the last one is kept
+    // around rather than calling the function again.
+    last_payload: RefCell<Option<Box<dyn Any + Send>>>,
+    payload: PhantomData<fn(&P)>,
+}
+
+impl<C: Callable, P: std::fmt::Debug + 'static, M: Matcher<P>> Matcher<C>
+    for PanicWithPayloadMatcher<P, M>
+{
+    fn match_value(&self, actual: &C) -> bool {
+        self.match_result(actual).is_match()
+    }
+
+    fn match_result(&self, actual: &C) -> MatchResult {
+        let payload = match catch_panic(actual) {
+            Some(payload) => payload,
+            None => return MatchResult::mismatched("did not panic"),
+        };
+        let mut last_payload = self.last_payload.borrow_mut();
+        let payload = match last_payload.insert(payload).downcast_ref::<P>() {
+            Some(payload) => payload,
+            None => {
+                return MatchResult::mismatched(format!(
+                    "panicked with a payload that is not a {}",
+                    std::any::type_name::<P>()
+                ))
+            }
+        };
+        match self.inner.match_result(payload) {
+            MatchResult::Matched => MatchResult::Matched,
+            MatchResult::Mismatched { reason } => {
+                let panicked = format!("panicked with payload {:?}", payload);
+                MatchResult::mismatched(match reason {
+                    Some(reason) => format!("{}\n{}", panicked, reason),
+                    None => panicked,
+                })
+            }
+        }
+    }
+
+    fn description(&self, _: &C) -> Description {
+        let verb = format!("panic with a {} payload", std::any::type_name::<P>());
+        let last_payload = self.last_payload.borrow();
+        match last_payload
+            .as_ref()
+            .and_then(|payload| payload.downcast_ref::<P>())
+        {
+            Some(payload) => {
+                let inner_desc = self.inner.description(payload);
+                Description {
+                    verb: format!("{} to {}", verb, inner_desc.verb),
+                    object: inner_desc.object,
+                }
+            }
+            None => Description { verb, object: None },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calling, catch_panic, panic, panic_with_message, panic_with_payload, CATCHING};
+    use crate::{
+        matchers::{equal, string::match_regex},
+        MatchResult, Matcher,
+    };
+    use std::cell::Cell;
+
+    #[test]
+    fn should_only_silence_panics_while_catching_them() {
+        let silenced = Cell::new(false);
+        assert!(catch_panic(&|| {
+            silenced.set(CATCHING.with(Cell::get));
+            panic!("boom")
+        })
+        .is_some());
+        assert!(silenced.get());
+        assert!(!CATCHING.with(Cell::get));
+    }
+
+    #[test]
+    fn should_catch_panics_in_nested_calls() {
+        assert!(catch_panic(&|| {
+            assert!(catch_panic(&|| panic!("inner")).is_some());
+            panic!("outer")
+        })
+        .is_some());
+        assert!(!CATCHING.with(Cell::get));
+    }
+
+    #[test]
+    fn panic_matcher_should_match_if_function_panics() {
+        assert!(panic().match_value(&calling(|| panic!("boom"))))
+    }
+
+    #[test]
+    fn panic_matcher_should_not_match_if_function_does_not_panic() {
+        assert_eq!(
+            panic().match_result(&calling(|| 42)),
+            MatchResult::mismatched("did not panic")
+        )
+    }
+
+    #[test]
+    fn panic_matcher_should_accept_function_pointers() {
+        fn boom() {
+            panic!("boom")
+        }
+        assert!(panic().match_value(&(boom as fn())))
+    }
+
+    #[test]
+    fn panic_matcher_should_describe_itself() {
+        let description = panic().description(&calling(|| 42));
+        assert_eq!(description.verb, String::from("panic"));
+        assert_eq!(description.object, None);
+    }
+
+    #[test]
+    fn panic_with_message_matcher_should_match_str_messages() {
+        assert!(panic_with_message(equal("boom")).match_value(&calling(|| panic!("boom"))))
+    }
+
+    #[test]
+    fn panic_with_message_matcher_should_match_formatted_messages() {
+        assert!(panic_with_message(match_regex(r"^answer: \d+$"))
+            .match_value(&calling(|| panic!("answer: {}", 42))))
+    }
+
+    #[test]
+    fn panic_with_message_matcher_should_explain_a_mismatching_message() {
+        assert_eq!(
+            panic_with_message(equal("bang")).match_result(&calling(|| panic!("boom"))),
+            MatchResult::mismatched("panicked with message \"boom\"")
+        )
+    }
+
+    #[test]
+    fn panic_with_message_matcher_should_not_match_non_string_payloads() {
+        assert_eq!(
+            panic_with_message(equal("42")).match_result(&calling(|| std::panic::panic_any(42u32))),
+            MatchResult::mismatched("panicked with a non-string payload")
+        )
+    }
+
+    #[test]
+    fn panic_with_message_matcher_should_describe_itself() {
+        let description = panic_with_message(equal("boom")).description(&calling(|| 42));
+        assert_eq!(
+            description.verb,
+            String::from("panic with a message to equal")
+        );
+        assert_eq!(description.object, Some(String::from("\"boom\"")));
+    }
+
+    #[test]
+    fn panic_with_payload_matcher_should_match_payloads_of_the_given_type() {
+        assert!(panic_with_payload::<u32, _>(equal(42))
+            .match_value(&calling(|| std::panic::panic_any(42u32))))
+    }
+
+    #[test]
+    fn panic_with_payload_matcher_should_explain_a_payload_of_another_type() {
+        assert_eq!(
+            panic_with_payload::<u32, _>(equal(42)).match_result(&calling(|| panic!("boom"))),
+            MatchResult::mismatched("panicked with a payload that is not a u32")
+        )
+    }
+
+    #[test]
+    fn panic_with_payload_matcher_should_describe_itself() {
+        let matcher = panic_with_payload::<u32, _>(equal(42));
+        let actual = calling(|| std::panic::panic_any(1u32));
+        assert!(!matcher.match_value(&actual));
+
+        let description = matcher.description(&actual);
+        assert_eq!(
+            description.verb,
+            String::from("panic with a u32 payload to equal")
+        );
+        assert_eq!(description.object, Some(String::from("42")));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Description, Matcher};
+use crate::{Description, MatchResult, Matcher};
 
 /// Matches if `actual` is a [`Result::Ok`].
 ///
@@ -22,6 +22,13 @@ impl<T, E> Matcher<Result<T, E>> for OkMatcher {
         actual.is_ok()
     }
 
+    fn match_result(&self, actual: &Result<T, E>) -> MatchResult {
+        match actual {
+            Ok(_) => MatchResult::Matched,
+            Err(_) => MatchResult::mismatched("was an Err"),
+        }
+    }
+
     fn description(&self, _: &Result<T, E>) -> Description {
         Description {
             verb: String::from("be Ok"),
@@ -52,6 +59,13 @@ impl<T, E> Matcher<Result<T, E>> for ErrMatcher {
         actual.is_err()
     }
 
+    fn match_result(&self, actual: &Result<T, E>) -> MatchResult {
+        match actual {
+            Err(_) => MatchResult::Matched,
+            Ok(_) => MatchResult::mismatched("was Ok"),
+        }
+    }
+
     fn description(&self, _: &Result<T, E>) -> Description {
         Description {
             verb: String::from("be an Err"),
@@ -63,7 +77,7 @@ impl<T, E> Matcher<Result<T, E>> for ErrMatcher {
 #[cfg(test)]
 mod tests {
     use super::{be_err, be_ok};
-    use crate::Matcher;
+    use crate::{MatchResult, Matcher};
 
     #[test]
     fn ok_matcher_should_match_if_actual_is_ok() {
@@ -75,6 +89,21 @@ mod tests {
         assert!(!be_ok().match_value(&Err::<u32, &str>("boo")))
     }
 
+    #[test]
+    fn ok_matcher_should_explain_a_mismatch() {
+        assert_eq!(
+            be_ok().match_result(&Err::<u32, &str>("boo")),
+            MatchResult::mismatched("was an Err")
+        )
+    }
+
+    #[test]
+    fn ok_and_err_matchers_should_accept_payloads_without_debug() {
+        struct Opaque;
+        assert!(be_ok().match_value(&Ok::<Opaque, Opaque>(Opaque)));
+        assert!(be_err().match_value(&Err::<Opaque, Opaque>(Opaque)));
+    }
+
     #[test]
     fn ok_matcher_should_describe_itself() {
         let description = be_ok().description(&Ok::<u32, &str>(42));
@@ -92,6 +121,14 @@ mod tests {
         assert!(!be_err().match_value(&Ok::<u32, &str>(42)))
     }
 
+    #[test]
+    fn err_matcher_should_explain_a_mismatch() {
+        assert_eq!(
+            be_err().match_result(&Ok::<u32, &str>(42)),
+            MatchResult::mismatched("was Ok")
+        )
+    }
+
     #[test]
     fn err_matcher_should_describe_itself() {
         let description = be_err().description(&Err::<u32, &str>("foo"));
@@ -1,13 +1,23 @@
-use crate::{Description, Matcher};
+use crate::matchers::logical::phrase;
+use crate::{Description, MatchResult, Matcher};
 
 use regex::Regex;
 
 /// Matches if the provided regular expression matches the actual value.
 ///
-/// `regex` needs to be a valid regular expression and the matching is performed using the
+/// `regex` can be a pattern, as a `&str` or a `String`, or an already compiled [`Regex`]. Patterns
+/// are compiled once, when the matcher is created, and the matching is performed using the
 /// [`is_match`] method.
 ///
-/// [`is_match`]: https://docs.rs/regex/1.3.7/regex/struct.Regex.html#method.is_match
+/// [`Regex`]: https://docs.rs/regex/1/regex/struct.Regex.html
+/// [`is_match`]: https://docs.rs/regex/1/regex/struct.Regex.html#method.is_match
+///
+/// # Panics
+///
+/// Panics if `regex` is not a valid regular expression. Use [`try_match_regex`] to handle the
+/// error instead.
+///
+/// [`try_match_regex`]: fn.try_match_regex.html
 ///
 /// # Examples
 ///
@@ -15,35 +25,416 @@ use regex::Regex;
 /// # use expect::{expect, matchers::string::match_regex};
 /// expect(&"abc-123").to(match_regex(r"^[a-z]{3}-\d{3}$"));
 /// expect(&"abc-123").to(match_regex(r"\d{3}"));
+/// expect(&"abc-123").to(match_regex(regex::Regex::new(r"\d{3}").unwrap()));
 /// ```
-pub fn match_regex<S: AsRef<str>>(regex: S) -> MatchRegexMatcher<S> {
-    MatchRegexMatcher { regex }
+#[track_caller]
+pub fn match_regex<'m, R: IntoRegex>(regex: R) -> MatchRegexMatcher<'m> {
+    match try_match_regex(regex) {
+        Ok(matcher) => matcher,
+        Err(error) => panic!("invalid regex passed to match_regex:\n{}", error),
+    }
 }
 
-pub struct MatchRegexMatcher<S> {
-    regex: S,
+/// Like [`match_regex`], but returns an error instead of panicking if `regex` is not a valid
+/// regular expression.
+///
+/// [`match_regex`]: fn.match_regex.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::string::try_match_regex};
+/// expect(&"abc-123").to(try_match_regex(r"\d{3}").unwrap());
+/// assert!(try_match_regex(r"\d{3").is_err());
+/// ```
+pub fn try_match_regex<'m, R: IntoRegex>(regex: R) -> Result<MatchRegexMatcher<'m>, regex::Error> {
+    Ok(MatchRegexMatcher {
+        regex: regex.into_regex()?,
+        captures: Vec::new(),
+    })
+}
+
+pub struct MatchRegexMatcher<'m> {
+    regex: Regex,
+    captures: Vec<(Group, CaptureMatcher<'m>)>,
+}
+
+type CaptureMatcher<'m> = Box<dyn for<'a> Matcher<&'a str> + 'm>;
+
+enum Group {
+    Name(String),
+    Index(usize),
 }
 
-impl<A: AsRef<str>, E: AsRef<str> + std::fmt::Debug> Matcher<A> for MatchRegexMatcher<E> {
+impl std::fmt::Display for Group {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Group::Name(name) => write!(f, "group {:?}", name),
+            Group::Index(index) => write!(f, "group {}", index),
+        }
+    }
+}
+
+impl<'m> MatchRegexMatcher<'m> {
+    /// Also requires the text captured by the group called `name` to match `matcher`.
+    ///
+    /// The group is taken from the leftmost match of the regex, and it must have captured
+    /// something for the matcher to match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the regex has no group called `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use expect::{expect, matchers::{equal, string::match_regex}};
+    /// expect(&"id=42").to(match_regex(r"id=(?P<id>\d+)").with_capture("id", equal("42")));
+    /// expect(&"id=41").not_to(match_regex(r"id=(?P<id>\d+)").with_capture("id", equal("42")));
+    /// ```
+    #[track_caller]
+    pub fn with_capture<M: for<'a> Matcher<&'a str> + 'm>(
+        mut self,
+        name: &str,
+        matcher: M,
+    ) -> Self {
+        if !self.regex.capture_names().any(|n| n == Some(name)) {
+            panic!(
+                "regex {:?} has no group named {:?}",
+                self.regex.as_str(),
+                name
+            );
+        }
+        self.captures
+            .push((Group::Name(name.to_string()), Box::new(matcher)));
+        self
+    }
+
+    /// Also requires the text captured by the group at position `index` to match `matcher`.
+    ///
+    /// Groups are numbered from 1, in the order of their opening parenthesis, while 0 is the whole
+    /// match. As with [`with_capture`], the group is taken from the leftmost match of the regex.
+    ///
+    /// [`with_capture`]: #method.with_capture
+    ///
+    /// # Panics
+    ///
+    /// Panics if the regex has no group at position `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use expect::{expect, matchers::{equal, string::match_regex}};
+    /// expect(&"3 apples").to(
+    ///     match_regex(r"(\d+) (\w+)")
+    ///         .with_capture_at(1, equal("3"))
+    ///         .with_capture_at(2, equal("apples")),
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn with_capture_at<M: for<'a> Matcher<&'a str> + 'm>(
+        mut self,
+        index: usize,
+        matcher: M,
+    ) -> Self {
+        if index >= self.regex.captures_len() {
+            panic!("regex {:?} has no group {}", self.regex.as_str(), index);
+        }
+        self.captures.push((Group::Index(index), Box::new(matcher)));
+        self
+    }
+}
+
+impl<A: AsRef<str>> Matcher<A> for MatchRegexMatcher<'_> {
     fn match_value(&self, actual: &A) -> bool {
-        if let Ok(compiled_regex) = self.regex.as_ref().parse::<Regex>() {
-            return compiled_regex.is_match(actual.as_ref());
+        self.match_result(actual).is_match()
+    }
+
+    fn match_result(&self, actual: &A) -> MatchResult {
+        if self.captures.is_empty() {
+            return MatchResult::from(self.regex.is_match(actual.as_ref()));
+        }
+        let captures = match self.regex.captures(actual.as_ref()) {
+            Some(captures) => captures,
+            None => return MatchResult::from(false),
+        };
+        for (group, matcher) in &self.captures {
+            let text = match group {
+                Group::Name(name) => captures.name(name),
+                Group::Index(index) => captures.get(*index),
+            };
+            let text = match text {
+                Some(text) => text.as_str(),
+                None => return MatchResult::mismatched(format!("{} did not capture", group)),
+            };
+            if let MatchResult::Mismatched { reason } = matcher.match_result(&text) {
+                let captured = format!("{} was {:?}", group, text);
+                return MatchResult::mismatched(match reason {
+                    Some(reason) => format!("{}\n{}", captured, reason),
+                    None => captured,
+                });
+            }
         }
-        false
+        MatchResult::Matched
     }
 
     fn description(&self, _: &A) -> Description {
+        let mut object = format!("{:?}", self.regex.as_str());
+        for (group, matcher) in &self.captures {
+            object.push_str(&format!(
+                " with {} to {}",
+                group,
+                phrase(matcher.description(&""))
+            ));
+        }
         Description {
             verb: String::from("match regex"),
-            object: Some(format!("{:?}", self.regex)),
+            object: Some(object),
+        }
+    }
+}
+
+/// A value that can be turned into a [`Regex`], as accepted by [`match_regex`]: either a pattern
+/// or an already compiled `Regex`.
+///
+/// [`Regex`]: https://docs.rs/regex/1/regex/struct.Regex.html
+/// [`match_regex`]: fn.match_regex.html
+pub trait IntoRegex {
+    fn into_regex(self) -> Result<Regex, regex::Error>;
+}
+
+impl IntoRegex for Regex {
+    fn into_regex(self) -> Result<Regex, regex::Error> {
+        Ok(self)
+    }
+}
+
+impl IntoRegex for &Regex {
+    fn into_regex(self) -> Result<Regex, regex::Error> {
+        Ok(self.clone())
+    }
+}
+
+impl IntoRegex for &str {
+    fn into_regex(self) -> Result<Regex, regex::Error> {
+        Regex::new(self)
+    }
+}
+
+impl IntoRegex for String {
+    fn into_regex(self) -> Result<Regex, regex::Error> {
+        Regex::new(&self)
+    }
+}
+
+impl IntoRegex for &String {
+    fn into_regex(self) -> Result<Regex, regex::Error> {
+        Regex::new(self)
+    }
+}
+
+/// Matches if the actual value starts with `prefix`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::string::start_with};
+/// expect(&"foobar").to(start_with("foo"));
+/// expect(&String::from("foobar")).not_to(start_with("bar"));
+/// ```
+pub fn start_with<S: AsRef<str>>(prefix: S) -> StartWithMatcher<S> {
+    StartWithMatcher { prefix }
+}
+
+pub struct StartWithMatcher<S> {
+    prefix: S,
+}
+
+impl<A: AsRef<str>, E: AsRef<str> + std::fmt::Debug> Matcher<A> for StartWithMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        actual.as_ref().starts_with(self.prefix.as_ref())
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("start with"),
+            object: Some(format!("{:?}", self.prefix)),
+        }
+    }
+}
+
+/// Matches if the actual value ends with `suffix`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::string::end_with};
+/// expect(&"foobar").to(end_with("bar"));
+/// expect(&String::from("foobar")).not_to(end_with("foo"));
+/// ```
+pub fn end_with<S: AsRef<str>>(suffix: S) -> EndWithMatcher<S> {
+    EndWithMatcher { suffix }
+}
+
+pub struct EndWithMatcher<S> {
+    suffix: S,
+}
+
+impl<A: AsRef<str>, E: AsRef<str> + std::fmt::Debug> Matcher<A> for EndWithMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        actual.as_ref().ends_with(self.suffix.as_ref())
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("end with"),
+            object: Some(format!("{:?}", self.suffix)),
+        }
+    }
+}
+
+/// Matches if the actual value contains `substring`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::string::contain_substring};
+/// expect(&"foobar").to(contain_substring("oba"));
+/// expect(&"foobar").not_to(contain_substring("baz"));
+/// ```
+pub fn contain_substring<S: AsRef<str>>(substring: S) -> ContainSubstringMatcher<S> {
+    ContainSubstringMatcher { substring }
+}
+
+pub struct ContainSubstringMatcher<S> {
+    substring: S,
+}
+
+impl<A: AsRef<str>, E: AsRef<str> + std::fmt::Debug> Matcher<A> for ContainSubstringMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        actual.as_ref().contains(self.substring.as_ref())
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("contain substring"),
+            object: Some(format!("{:?}", self.substring)),
+        }
+    }
+}
+
+/// Matches if the actual value is equal to `expected`, ignoring case.
+///
+/// Case is ignored following Unicode rules, so that for example `"STRASSE"` is equal to
+/// `"straße"`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::string::equal_ignoring_case};
+/// expect(&"FooBar").to(equal_ignoring_case("foobar"));
+/// expect(&"Straße").to(equal_ignoring_case("STRASSE"));
+/// expect(&"foo").not_to(equal_ignoring_case("bar"));
+/// ```
+pub fn equal_ignoring_case<S: AsRef<str>>(expected: S) -> EqualIgnoringCaseMatcher<S> {
+    EqualIgnoringCaseMatcher { expected }
+}
+
+pub struct EqualIgnoringCaseMatcher<S> {
+    expected: S,
+}
+
+fn fold_case(string: &str) -> String {
+    // Upper-casing first expands characters like 'ß' to their multi-character forms, which gets
+    // closer to full case folding than lower-casing alone.
+    string.to_uppercase().to_lowercase()
+}
+
+impl<A: AsRef<str>, E: AsRef<str> + std::fmt::Debug> Matcher<A> for EqualIgnoringCaseMatcher<E> {
+    fn match_value(&self, actual: &A) -> bool {
+        fold_case(actual.as_ref()) == fold_case(self.expected.as_ref())
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("equal ignoring case"),
+            object: Some(format!("{:?}", self.expected)),
+        }
+    }
+}
+
+/// Matches if the actual value is equal to `expected`, ignoring leading and trailing whitespace
+/// and treating any run of whitespace as a single space.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::string::equal_ignoring_whitespace};
+/// expect(&"  foo \n\t bar ").to(equal_ignoring_whitespace("foo bar"));
+/// expect(&"foobar").not_to(equal_ignoring_whitespace("foo bar"));
+/// ```
+pub fn equal_ignoring_whitespace<S: AsRef<str>>(expected: S) -> EqualIgnoringWhitespaceMatcher<S> {
+    EqualIgnoringWhitespaceMatcher { expected }
+}
+
+pub struct EqualIgnoringWhitespaceMatcher<S> {
+    expected: S,
+}
+
+impl<A: AsRef<str>, E: AsRef<str> + std::fmt::Debug> Matcher<A>
+    for EqualIgnoringWhitespaceMatcher<E>
+{
+    fn match_value(&self, actual: &A) -> bool {
+        actual
+            .as_ref()
+            .split_whitespace()
+            .eq(self.expected.as_ref().split_whitespace())
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("equal ignoring whitespace"),
+            object: Some(format!("{:?}", self.expected)),
+        }
+    }
+}
+
+/// Matches if the actual value is empty or only contains whitespace.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::string::be_blank};
+/// expect(&"").to(be_blank());
+/// expect(&" \n\t").to(be_blank());
+/// expect(&" foo ").not_to(be_blank());
+/// ```
+pub fn be_blank() -> BeBlankMatcher {
+    BeBlankMatcher {}
+}
+
+pub struct BeBlankMatcher {}
+
+impl<A: AsRef<str>> Matcher<A> for BeBlankMatcher {
+    fn match_value(&self, actual: &A) -> bool {
+        actual.as_ref().trim().is_empty()
+    }
+
+    fn description(&self, _: &A) -> Description {
+        Description {
+            verb: String::from("be blank"),
+            object: None,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::match_regex;
-    use crate::Matcher;
+    use super::{
+        be_blank, contain_substring, end_with, equal_ignoring_case, equal_ignoring_whitespace,
+        match_regex, start_with, try_match_regex,
+    };
+    use crate::{matchers::equal, MatchResult, Matcher};
 
     #[test]
     fn should_match_if_actual_matches_with_regex() {
@@ -61,4 +452,177 @@ mod tests {
         assert_eq!(description.verb, String::from("match regex"));
         assert_eq!(description.object, Some(String::from("\"foo\"")));
     }
+
+    #[test]
+    fn match_regex_matcher_should_accept_compiled_regexes() {
+        let regex = regex::Regex::new("foo.*").unwrap();
+        assert!(match_regex(&regex).match_value(&"foobar"));
+        assert!(!match_regex(regex).match_value(&"bar"));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid regex passed to match_regex")]
+    fn match_regex_should_panic_on_invalid_patterns() {
+        match_regex("foo(");
+    }
+
+    #[test]
+    fn try_match_regex_should_return_an_error_on_invalid_patterns() {
+        assert!(try_match_regex("foo(").is_err());
+        assert!(try_match_regex(String::from("foo"))
+            .unwrap()
+            .match_value(&"foo"));
+    }
+
+    #[test]
+    fn match_regex_matcher_should_match_named_captures() {
+        let matcher = match_regex(r"id=(?P<id>\d+)").with_capture("id", equal("42"));
+        assert!(matcher.match_value(&"user id=42"));
+        assert!(!matcher.match_value(&"user"));
+    }
+
+    #[test]
+    fn match_regex_matcher_should_explain_a_mismatching_capture() {
+        assert_eq!(
+            match_regex(r"id=(?P<id>\d+)")
+                .with_capture("id", equal("42"))
+                .match_result(&"id=41"),
+            MatchResult::mismatched("group \"id\" was \"41\"")
+        )
+    }
+
+    #[test]
+    fn match_regex_matcher_should_match_positional_captures() {
+        let matcher = match_regex(r"(\d+) (\w+)")
+            .with_capture_at(1, equal("3"))
+            .with_capture_at(2, match_regex("^a"));
+        assert!(matcher.match_value(&"3 apples"));
+        assert_eq!(
+            matcher.match_result(&"3 pears"),
+            MatchResult::mismatched("group 2 was \"pears\"")
+        );
+    }
+
+    #[test]
+    fn match_regex_matcher_should_explain_a_group_that_did_not_capture() {
+        assert_eq!(
+            match_regex(r"a(b)?")
+                .with_capture_at(1, equal("b"))
+                .match_result(&"a"),
+            MatchResult::mismatched("group 1 did not capture")
+        )
+    }
+
+    #[test]
+    fn match_regex_matcher_should_accept_capture_matchers_borrowing_locals() {
+        let expected = String::from("42");
+        let needle = String::from("2");
+        let matcher = match_regex(r"id=(?P<id>\d+)")
+            .with_capture("id", equal(expected.as_str()))
+            .with_capture_at(1, contain_substring(&needle));
+        assert!(matcher.match_value(&"id=42"));
+        assert!(!matcher.match_value(&"id=43"));
+    }
+
+    #[test]
+    #[should_panic(expected = "has no group named \"name\"")]
+    fn with_capture_should_panic_on_unknown_groups() {
+        match_regex(r"id=(?P<id>\d+)").with_capture("name", equal("42"));
+    }
+
+    #[test]
+    fn match_regex_matcher_should_describe_its_captures() {
+        let description = match_regex(r"(?P<id>\d+)")
+            .with_capture("id", equal("42"))
+            .with_capture_at(0, start_with("4"))
+            .description(&"bar");
+        assert_eq!(description.verb, String::from("match regex"));
+        assert_eq!(
+            description.object,
+            Some(String::from(
+                r#""(?P<id>\\d+)" with group "id" to equal "42" with group 0 to start with "4""#
+            ))
+        );
+    }
+
+    #[test]
+    fn start_with_matcher_should_match_if_actual_starts_with_prefix() {
+        assert!(start_with("foo").match_value(&"foobar"));
+        assert!(!start_with("bar").match_value(&String::from("foobar")));
+    }
+
+    #[test]
+    fn start_with_matcher_should_describe_itself() {
+        let description = start_with("foo").description(&"bar");
+        assert_eq!(description.verb, String::from("start with"));
+        assert_eq!(description.object, Some(String::from("\"foo\"")));
+    }
+
+    #[test]
+    fn end_with_matcher_should_match_if_actual_ends_with_suffix() {
+        assert!(end_with("bar").match_value(&"foobar"));
+        assert!(!end_with("foo").match_value(&"foobar"));
+    }
+
+    #[test]
+    fn end_with_matcher_should_describe_itself() {
+        let description = end_with("foo").description(&"bar");
+        assert_eq!(description.verb, String::from("end with"));
+        assert_eq!(description.object, Some(String::from("\"foo\"")));
+    }
+
+    #[test]
+    fn contain_substring_matcher_should_match_if_actual_contains_substring() {
+        assert!(contain_substring("oba").match_value(&"foobar"));
+        assert!(!contain_substring("baz").match_value(&"foobar"));
+    }
+
+    #[test]
+    fn contain_substring_matcher_should_describe_itself() {
+        let description = contain_substring("foo").description(&"bar");
+        assert_eq!(description.verb, String::from("contain substring"));
+        assert_eq!(description.object, Some(String::from("\"foo\"")));
+    }
+
+    #[test]
+    fn equal_ignoring_case_matcher_should_match_regardless_of_case() {
+        assert!(equal_ignoring_case("foobar").match_value(&"FooBAR"));
+        assert!(equal_ignoring_case("ΣΊΣΥΦΟΣ").match_value(&"σίσυφος"));
+        assert!(equal_ignoring_case("STRASSE").match_value(&"straße"));
+        assert!(!equal_ignoring_case("foo").match_value(&"bar"));
+    }
+
+    #[test]
+    fn equal_ignoring_case_matcher_should_describe_itself() {
+        let description = equal_ignoring_case("foo").description(&"bar");
+        assert_eq!(description.verb, String::from("equal ignoring case"));
+        assert_eq!(description.object, Some(String::from("\"foo\"")));
+    }
+
+    #[test]
+    fn equal_ignoring_whitespace_matcher_should_match_regardless_of_whitespace() {
+        assert!(equal_ignoring_whitespace("foo bar").match_value(&" foo\n\t bar  "));
+        assert!(!equal_ignoring_whitespace("foo bar").match_value(&"foobar"));
+    }
+
+    #[test]
+    fn equal_ignoring_whitespace_matcher_should_describe_itself() {
+        let description = equal_ignoring_whitespace("foo").description(&"bar");
+        assert_eq!(description.verb, String::from("equal ignoring whitespace"));
+        assert_eq!(description.object, Some(String::from("\"foo\"")));
+    }
+
+    #[test]
+    fn be_blank_matcher_should_match_if_actual_is_empty_or_whitespace() {
+        assert!(be_blank().match_value(&""));
+        assert!(be_blank().match_value(&String::from(" \n\t")));
+        assert!(!be_blank().match_value(&" foo "));
+    }
+
+    #[test]
+    fn be_blank_matcher_should_describe_itself() {
+        let description = be_blank().description(&"bar");
+        assert_eq!(description.verb, String::from("be blank"));
+        assert_eq!(description.object, None);
+    }
 }
@@ -0,0 +1,351 @@
+use crate::{expect, ExpectationFailure, Matcher};
+
+use std::time::{Duration, Instant};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+const DEFAULT_DURATION: Duration = Duration::from_millis(100);
+const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Creates an [`Eventually`] expectation, which polls `actual` until it matches.
+///
+/// [`Eventually`]: struct.Eventually.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{eventually, matchers::equal};
+/// # use std::time::Duration;
+/// let mut attempts = 0;
+/// eventually(|| {
+///     attempts += 1;
+///     attempts
+/// })
+/// .within(Duration::from_millis(500))
+/// .polling_every(Duration::from_millis(1))
+/// .to(equal(3));
+/// ```
+pub fn eventually<T, F: FnMut() -> T>(actual: F) -> Eventually<F> {
+    Eventually {
+        actual,
+        timeout: DEFAULT_TIMEOUT,
+        interval: DEFAULT_POLLING_INTERVAL,
+    }
+}
+
+/// An expectation on a value that is expected to change over time. It wraps a function producing
+/// the value, and calls it repeatedly until the value matches or a timeout expires.
+///
+/// The default timeout is 1 second, and the default polling interval is 10 milliseconds.
+pub struct Eventually<F> {
+    actual: F,
+    timeout: Duration,
+    interval: Duration,
+}
+
+impl<F> Eventually<F> {
+    /// Sets how long to keep polling before failing.
+    pub fn within(self, timeout: Duration) -> Self {
+        Eventually { timeout, ..self }
+    }
+
+    /// Sets how long to wait between two attempts.
+    pub fn polling_every(self, interval: Duration) -> Self {
+        Eventually { interval, ..self }
+    }
+}
+
+impl<T: std::fmt::Debug, F: FnMut() -> T> Eventually<F> {
+    /// Polls the actual value until it matches a [`Matcher`].
+    ///
+    /// If the timeout expires first, this method will [`panic!`] with a failure message based on
+    /// the last value produced and on the number of attempts.
+    ///
+    /// [`Matcher`]: trait.Matcher.html
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic.html
+    #[track_caller]
+    pub fn to<M: Matcher<T>>(self, matcher: M) {
+        if let Err(failure) = self.try_to(matcher) {
+            panic!("{}", failure)
+        }
+    }
+
+    /// Polls the actual value until it doesn't match a [`Matcher`].
+    ///
+    /// If the timeout expires first, this method will [`panic!`] with a failure message based on
+    /// the last value produced and on the number of attempts.
+    ///
+    /// [`Matcher`]: trait.Matcher.html
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic.html
+    #[track_caller]
+    pub fn not_to<M: Matcher<T>>(self, matcher: M) {
+        if let Err(failure) = self.try_not_to(matcher) {
+            panic!("{}", failure)
+        }
+    }
+
+    /// Like [`to`], but returns an [`ExpectationFailure`] instead of panicking.
+    ///
+    /// [`to`]: #method.to
+    /// [`ExpectationFailure`]: struct.ExpectationFailure.html
+    pub fn try_to<M: Matcher<T>>(self, matcher: M) -> Result<(), ExpectationFailure> {
+        self.poll(|actual| expect(actual).try_to(&matcher))
+    }
+
+    /// Like [`not_to`], but returns an [`ExpectationFailure`] instead of panicking.
+    ///
+    /// [`not_to`]: #method.not_to
+    /// [`ExpectationFailure`]: struct.ExpectationFailure.html
+    pub fn try_not_to<M: Matcher<T>>(self, matcher: M) -> Result<(), ExpectationFailure> {
+        self.poll(|actual| expect(actual).try_not_to(&matcher))
+    }
+
+    fn poll<C>(mut self, check: C) -> Result<(), ExpectationFailure>
+    where
+        C: Fn(&T) -> Result<(), ExpectationFailure>,
+    {
+        let start = Instant::now();
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let failure = match check(&(self.actual)()) {
+                Ok(()) => return Ok(()),
+                Err(failure) => failure,
+            };
+
+            let elapsed = start.elapsed();
+            if elapsed >= self.timeout {
+                let note = format!("still failing after {} attempts in {:?}", attempts, elapsed);
+                return Err(with_note(failure, note));
+            }
+            std::thread::sleep(self.interval.min(self.timeout - elapsed));
+        }
+    }
+}
+
+/// Creates a [`Consistently`] expectation, which polls `actual` to check that it keeps matching.
+///
+/// [`Consistently`]: struct.Consistently.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{consistently, matchers::equal};
+/// # use std::time::Duration;
+/// let cache = vec![1, 2, 3];
+/// consistently(|| cache.len())
+///     .during(Duration::from_millis(50))
+///     .polling_every(Duration::from_millis(5))
+///     .to(equal(3));
+/// ```
+pub fn consistently<T, F: FnMut() -> T>(actual: F) -> Consistently<F> {
+    Consistently {
+        actual,
+        duration: DEFAULT_DURATION,
+        interval: DEFAULT_POLLING_INTERVAL,
+    }
+}
+
+/// An expectation on a value that is expected not to change over time. It wraps a function
+/// producing the value, and calls it repeatedly for a given duration, failing as soon as the value
+/// stops matching.
+///
+/// The default duration is 100 milliseconds, and the default polling interval is 10 milliseconds.
+pub struct Consistently<F> {
+    actual: F,
+    duration: Duration,
+    interval: Duration,
+}
+
+impl<F> Consistently<F> {
+    /// Sets how long to keep polling for.
+    pub fn during(self, duration: Duration) -> Self {
+        Consistently { duration, ..self }
+    }
+
+    /// Sets how long to wait between two attempts.
+    pub fn polling_every(self, interval: Duration) -> Self {
+        Consistently { interval, ..self }
+    }
+}
+
+impl<T: std::fmt::Debug, F: FnMut() -> T> Consistently<F> {
+    /// Polls the actual value, checking that it keeps matching a [`Matcher`].
+    ///
+    /// As soon as the value stops matching, this method will [`panic!`] with a failure message
+    /// based on the offending value and on the time elapsed.
+    ///
+    /// [`Matcher`]: trait.Matcher.html
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic.html
+    #[track_caller]
+    pub fn to<M: Matcher<T>>(self, matcher: M) {
+        if let Err(failure) = self.try_to(matcher) {
+            panic!("{}", failure)
+        }
+    }
+
+    /// Polls the actual value, checking that it never matches a [`Matcher`].
+    ///
+    /// As soon as the value matches, this method will [`panic!`] with a failure message based on
+    /// the offending value and on the time elapsed.
+    ///
+    /// [`Matcher`]: trait.Matcher.html
+    /// [`panic!`]: https://doc.rust-lang.org/std/macro.panic.html
+    #[track_caller]
+    pub fn not_to<M: Matcher<T>>(self, matcher: M) {
+        if let Err(failure) = self.try_not_to(matcher) {
+            panic!("{}", failure)
+        }
+    }
+
+    /// Like [`to`], but returns an [`ExpectationFailure`] instead of panicking.
+    ///
+    /// [`to`]: #method.to
+    /// [`ExpectationFailure`]: struct.ExpectationFailure.html
+    pub fn try_to<M: Matcher<T>>(self, matcher: M) -> Result<(), ExpectationFailure> {
+        self.poll(|actual| expect(actual).try_to(&matcher))
+    }
+
+    /// Like [`not_to`], but returns an [`ExpectationFailure`] instead of panicking.
+    ///
+    /// [`not_to`]: #method.not_to
+    /// [`ExpectationFailure`]: struct.ExpectationFailure.html
+    pub fn try_not_to<M: Matcher<T>>(self, matcher: M) -> Result<(), ExpectationFailure> {
+        self.poll(|actual| expect(actual).try_not_to(&matcher))
+    }
+
+    fn poll<C>(mut self, check: C) -> Result<(), ExpectationFailure>
+    where
+        C: Fn(&T) -> Result<(), ExpectationFailure>,
+    {
+        let start = Instant::now();
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            if let Err(failure) = check(&(self.actual)()) {
+                let note = format!(
+                    "failed after {:?}, at attempt {}",
+                    start.elapsed(),
+                    attempts
+                );
+                return Err(with_note(failure, note));
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= self.duration {
+                return Ok(());
+            }
+            std::thread::sleep(self.interval.min(self.duration - elapsed));
+        }
+    }
+}
+
+fn with_note(failure: ExpectationFailure, note: String) -> ExpectationFailure {
+    let reason = match failure.reason {
+        Some(reason) => format!("{}\n{}", reason, note),
+        None => note,
+    };
+    ExpectationFailure {
+        reason: Some(reason),
+        ..failure
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{consistently, eventually};
+    use crate::matchers::equal;
+
+    use std::time::Duration;
+
+    fn counter() -> impl FnMut() -> u32 {
+        let mut count = 0;
+        move || {
+            count += 1;
+            count
+        }
+    }
+
+    #[test]
+    fn eventually_to_should_not_panic_if_the_value_matches_in_time() {
+        eventually(counter())
+            .polling_every(Duration::from_millis(1))
+            .to(equal(5))
+    }
+
+    #[test]
+    #[should_panic(expected = "still failing after")]
+    fn eventually_to_should_panic_if_the_value_does_not_match_in_time() {
+        eventually(counter())
+            .within(Duration::from_millis(20))
+            .to(equal(0))
+    }
+
+    #[test]
+    fn eventually_not_to_should_not_panic_if_the_value_stops_matching_in_time() {
+        eventually(counter())
+            .polling_every(Duration::from_millis(1))
+            .not_to(equal(1))
+    }
+
+    #[test]
+    fn eventually_should_report_the_last_value_and_the_number_of_attempts() {
+        let failure = eventually(counter())
+            .within(Duration::from_millis(50))
+            .polling_every(Duration::from_millis(20))
+            .try_to(equal(0))
+            .unwrap_err();
+
+        let attempts = failure.actual.parse::<u32>().unwrap();
+        assert!(attempts > 1);
+        assert!(failure
+            .reason
+            .unwrap()
+            .starts_with(&format!("still failing after {} attempts in ", attempts)));
+    }
+
+    #[test]
+    fn eventually_should_try_at_least_once() {
+        let result = eventually(counter())
+            .within(Duration::from_millis(0))
+            .try_to(equal(1));
+        assert_eq!(result, Ok(()))
+    }
+
+    #[test]
+    fn consistently_to_should_not_panic_if_the_value_keeps_matching() {
+        consistently(|| 42)
+            .during(Duration::from_millis(20))
+            .polling_every(Duration::from_millis(1))
+            .to(equal(42))
+    }
+
+    #[test]
+    #[should_panic(expected = "failed after")]
+    fn consistently_to_should_panic_if_the_value_stops_matching() {
+        consistently(counter())
+            .polling_every(Duration::from_millis(1))
+            .to(equal(1))
+    }
+
+    #[test]
+    fn consistently_not_to_should_not_panic_if_the_value_never_matches() {
+        consistently(counter())
+            .during(Duration::from_millis(20))
+            .polling_every(Duration::from_millis(1))
+            .not_to(equal(0))
+    }
+
+    #[test]
+    fn consistently_should_report_the_offending_value_and_the_elapsed_time() {
+        let failure = consistently(counter())
+            .during(Duration::from_secs(10))
+            .polling_every(Duration::from_millis(1))
+            .try_not_to(equal(3))
+            .unwrap_err();
+
+        assert_eq!(failure.actual, String::from("3"));
+        let reason = failure.reason.unwrap();
+        assert!(reason.starts_with("failed after "));
+        assert!(reason.ends_with(", at attempt 3"));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{expect, Expectation, ExpectationFailure, Matcher};
+
+use std::{cell::RefCell, panic::Location};
+
+/// Runs `f` in a soft-assertion scope.
+///
+/// Expectations created through the [`SoftExpectations`] passed to `f` don't panic when they fail:
+/// their failures are collected instead, and reported all together in a single [`panic!`] once `f`
+/// returns, each with the line that recorded it.
+///
+/// [`SoftExpectations`]: struct.SoftExpectations.html
+/// [`panic!`]: https://doc.rust-lang.org/std/macro.panic.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{soft, matchers::{equal, collection::contain}};
+/// soft(|s| {
+///     s.expect(&(2 + 2)).to(equal(4));
+///     s.expect(&vec![1, 2, 3]).not_to(contain(4));
+/// });
+/// ```
+///
+/// ```should_panic
+/// # use expect::{soft, matchers::equal};
+/// // panics with "2 expectations failed", listing both failures
+/// soft(|s| {
+///     s.expect(&(2 + 2)).to(equal(5));
+///     s.expect(&(3 + 3)).to(equal(7));
+/// });
+/// ```
+#[track_caller]
+pub fn soft<F: FnOnce(&SoftExpectations)>(f: F) {
+    let expectations = SoftExpectations::new();
+    f(&expectations);
+    expectations.verify();
+}
+
+/// A soft-assertion scope, collecting the failures of the expectations created through it.
+///
+/// See [`soft`].
+///
+/// [`soft`]: fn.soft.html
+pub struct SoftExpectations {
+    failures: RefCell<Vec<(ExpectationFailure, &'static Location<'static>)>>,
+}
+
+impl SoftExpectations {
+    fn new() -> Self {
+        SoftExpectations {
+            failures: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Creates a [`SoftExpectation`], whose failures will be collected by this scope.
+    ///
+    /// [`SoftExpectation`]: struct.SoftExpectation.html
+    pub fn expect<'s, 'a, T>(&'s self, actual: &'a T) -> SoftExpectation<'s, 'a, T> {
+        SoftExpectation {
+            scope: self,
+            expectation: expect(actual),
+        }
+    }
+
+    fn record(&self, result: Result<(), ExpectationFailure>, location: &'static Location<'static>) {
+        if let Err(failure) = result {
+            self.failures.borrow_mut().push((failure, location));
+        }
+    }
+
+    #[track_caller]
+    fn verify(self) {
+        let failures = self.failures.into_inner();
+        if !failures.is_empty() {
+            panic!("{}", failures_message(&failures))
+        }
+    }
+}
+
+/// An expectation created in a soft-assertion scope. Unlike [`Expectation`], it doesn't panic when
+/// it fails.
+///
+/// [`Expectation`]: struct.Expectation.html
+pub struct SoftExpectation<'s, 'a, T> {
+    scope: &'s SoftExpectations,
+    expectation: Expectation<'a, T>,
+}
+
+impl<'s, 'a, T: std::fmt::Debug> SoftExpectation<'s, 'a, T> {
+    /// Checks the actual value agains a [`Matcher`], looking for a match, and records a failure if
+    /// there isn't one.
+    ///
+    /// [`Matcher`]: trait.Matcher.html
+    #[track_caller]
+    pub fn to<M: Matcher<T>>(&self, matcher: M) {
+        self.scope
+            .record(self.expectation.try_to(matcher), Location::caller())
+    }
+
+    /// Checks the actual value agains a [`Matcher`], looking for the lack of a match, and records a
+    /// failure if there is one.
+    ///
+    /// [`Matcher`]: trait.Matcher.html
+    #[track_caller]
+    pub fn not_to<M: Matcher<T>>(&self, matcher: M) {
+        self.scope
+            .record(self.expectation.try_not_to(matcher), Location::caller())
+    }
+}
+
+/// Lists the failures in the order they happened, each followed by the line that recorded it.
+fn failures_message(failures: &[(ExpectationFailure, &'static Location<'static>)]) -> String {
+    let noun = if failures.len() == 1 {
+        "expectation"
+    } else {
+        "expectations"
+    };
+    let mut message = format!("{} {} failed:\n", failures.len(), noun);
+    for (i, (failure, location)) in failures.iter().enumerate() {
+        message.push_str(&format!("\n{}) {}\tat {}\n", i + 1, failure, location));
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::soft;
+    use crate::matchers::equal;
+
+    #[test]
+    fn should_not_panic_if_all_expectations_pass() {
+        soft(|s| {
+            s.expect(&(2 + 2)).to(equal(4));
+            s.expect(&(2 + 2)).not_to(equal(5));
+        })
+    }
+
+    #[test]
+    #[should_panic(expected = "1 expectation failed")]
+    fn should_panic_if_an_expectation_fails() {
+        soft(|s| {
+            s.expect(&(2 + 2)).to(equal(4));
+            s.expect(&(2 + 2)).not_to(equal(4));
+        })
+    }
+
+    #[test]
+    fn should_report_all_failures_in_order() {
+        let result = std::panic::catch_unwind(|| {
+            soft(|s| {
+                s.expect(&(2 + 2)).to(equal(5));
+                s.expect(&(2 + 2)).to(equal(4));
+                s.expect(&(3 + 3)).to(equal(7));
+            })
+        });
+
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("2 expectations failed:\n"));
+        let first = message.find("1) Expectation failed").unwrap();
+        let second = message.find("2) Expectation failed").unwrap();
+        assert!(first < second);
+        assert!(message[first..second].contains("\t\t5\n"));
+        assert!(message[second..].contains("\t\t7\n"));
+    }
+
+    #[test]
+    fn should_report_where_each_failure_was_recorded() {
+        let mut lines = Vec::new();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            soft(|s| {
+                lines.push(line!() + 1);
+                s.expect(&(2 + 2)).to(equal(5));
+                lines.push(line!() + 1);
+                s.expect(&(2 + 2)).not_to(equal(4));
+            })
+        }));
+
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        let first = message.find("1) Expectation failed").unwrap();
+        let second = message.find("2) Expectation failed").unwrap();
+        let location = |line| format!("\tat {}:{}:", file!(), line);
+        assert!(message[first..second].contains(&location(lines[0])));
+        assert!(message[second..].contains(&location(lines[1])));
+    }
+}